@@ -0,0 +1,131 @@
+mod or_repeat;
+pub use or_repeat::OrRepeat;
+
+mod or_repeat_with;
+pub use or_repeat_with::OrRepeatWith;
+
+#[cfg(feature = "alloc")]
+mod or_cycle;
+#[cfg(feature = "alloc")]
+pub use or_cycle::{cycle_buffer, CycleBuffer, OrCycle};
+
+mod restart_with;
+pub use restart_with::{restart_with, RestartWith};
+
+mod successors;
+pub use successors::{iterate, successors_infinite, SuccessorsInfinite};
+
+mod unfold;
+pub use unfold::{unfold_infinite, UnfoldInfinite};
+
+mod cycle_slice;
+pub use cycle_slice::{cycle_array, cycle_slice, CycleSlice};
+
+mod chain_infinite;
+pub use chain_infinite::ChainInfinite;
+
+mod peekable;
+pub use peekable::InfinitePeekable;
+
+mod lookahead;
+pub use lookahead::Lookahead;
+
+#[cfg(feature = "alloc")]
+mod multipeek;
+#[cfg(feature = "alloc")]
+pub use multipeek::MultiPeek;
+
+mod put_back;
+pub use put_back::{put_back, PutBack};
+#[cfg(feature = "alloc")]
+pub use put_back::{put_back_n, PutBackN};
+
+#[cfg(feature = "alloc")]
+mod tee;
+#[cfg(feature = "alloc")]
+pub use tee::Tee;
+
+#[cfg(feature = "std")]
+mod tee_sync;
+#[cfg(feature = "std")]
+pub use tee_sync::TeeSync;
+
+#[cfg(feature = "std")]
+mod broadcast;
+#[cfg(feature = "std")]
+pub use broadcast::Broadcast;
+
+#[cfg(feature = "alloc")]
+mod memoized;
+#[cfg(feature = "alloc")]
+pub use memoized::Memoized;
+
+mod take_exact;
+pub use take_exact::TakeExact;
+
+#[cfg(feature = "alloc")]
+mod chunks;
+#[cfg(feature = "alloc")]
+pub use chunks::Chunks;
+
+mod array_chunks;
+pub use array_chunks::ArrayChunksInfinite;
+
+mod windows;
+pub use windows::Windows;
+
+mod tuple_windows;
+pub use tuple_windows::TupleWindows;
+
+mod tuples;
+pub use tuples::Tuples;
+
+#[cfg(feature = "alloc")]
+mod chunk_by;
+#[cfg(feature = "alloc")]
+pub use chunk_by::ChunkBy;
+
+mod dedup;
+pub use dedup::{Dedup, DedupBy};
+
+mod run_length;
+pub use run_length::{RunLengthDecode, RunLengthEncode};
+
+mod delta;
+pub use delta::{DeltaDecode, DeltaEncode};
+
+mod scan_infinite;
+pub use scan_infinite::ScanInfinite;
+
+mod zip_with;
+pub use zip_with::ZipWith;
+
+mod interleave;
+pub use interleave::Interleave;
+
+mod round_robin;
+#[cfg(feature = "alloc")]
+pub use round_robin::round_robin_vec;
+pub use round_robin::{round_robin, weighted_round_robin, RoundRobin, WeightedRoundRobin};
+
+mod merge;
+pub use merge::{Merge, MergeBy};
+
+#[cfg(feature = "alloc")]
+mod kmerge;
+#[cfg(feature = "alloc")]
+pub use kmerge::{kmerge, KMerge};
+
+mod set_ops;
+pub use set_ops::{Difference, Intersection, Union};
+
+#[cfg(feature = "alloc")]
+mod diagonal_product;
+#[cfg(feature = "alloc")]
+pub use diagonal_product::DiagonalProduct;
+
+mod switch;
+pub use switch::{switch, Switch};
+
+mod inspect_every;
+pub use inspect_every::InspectEvery;
@@ -0,0 +1,42 @@
+use crate::InfiniteIterator;
+use crate::InfiniteIteratorExt;
+
+/// An infinite iterator over consecutive fixed-size arrays of another iterator's items.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::array_chunks_infinite`](crate::InfiniteIteratorExt::array_chunks_infinite).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ArrayChunksInfinite<I, const N: usize> {
+    iter: I,
+}
+
+impl<I, const N: usize> ArrayChunksInfinite<I, N> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter }
+    }
+
+    /// Extract the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: InfiniteIterator, const N: usize> Iterator for ArrayChunksInfinite<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = self.iter.size_hint().0.checked_div(N);
+        (lower.unwrap_or(usize::MAX), None)
+    }
+}
+
+impl<I: InfiniteIterator, const N: usize> InfiniteIterator for ArrayChunksInfinite<I, N> {
+    fn next_infinite(&mut self) -> Self::Item {
+        self.iter.next_array()
+    }
+}
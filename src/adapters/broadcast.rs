@@ -0,0 +1,60 @@
+use crate::InfiniteIterator;
+use std::sync::mpsc;
+use std::thread;
+use std::vec::Vec;
+
+/// One of several handles receiving every item of an infinite iterator
+/// that is being driven on a background thread.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::broadcast`](crate::InfiniteIteratorExt::broadcast).
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Broadcast<T> {
+    receiver: mpsc::Receiver<T>,
+}
+
+impl<T> Broadcast<T> {
+    pub(crate) fn spawn<I>(mut iter: I, n: usize, capacity: usize) -> Vec<Self>
+    where
+        I: InfiniteIterator<Item = T> + Send + 'static,
+        T: Clone + Send + 'static,
+    {
+        let (mut senders, receivers): (Vec<_>, Vec<_>) =
+            (0..n).map(|_| mpsc::sync_channel(capacity)).unzip();
+
+        thread::spawn(move || {
+            while !senders.is_empty() {
+                let item = iter.next_infinite();
+                senders.retain(|sender| sender.send(item.clone()).is_ok());
+            }
+        });
+
+        receivers
+            .into_iter()
+            .map(|receiver| Self { receiver })
+            .collect()
+    }
+}
+
+impl<T> Iterator for Broadcast<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T> InfiniteIterator for Broadcast<T> {
+    fn next_infinite(&mut self) -> Self::Item {
+        match self.receiver.recv() {
+            Ok(item) => item,
+            // The pump thread only exits early if the source iterator panics.
+            Err(mpsc::RecvError) => panic!("broadcast source iterator panicked"),
+        }
+    }
+}
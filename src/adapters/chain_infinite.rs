@@ -0,0 +1,75 @@
+use crate::InfiniteDoubleEndedIterator;
+use crate::InfiniteIterator;
+
+/// An iterator that chains an infinite iterator with another iterator.
+///
+/// Since the first iterator never ends,
+/// iterating forwards never reaches the second iterator.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::chain_infinite`](crate::InfiniteIteratorExt::chain_infinite).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChainInfinite<A, B> {
+    a: A,
+    /// Set to `None` once it ends when iterating backwards.
+    b: Option<B>,
+}
+
+impl<A, B> ChainInfinite<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self { a, b: Some(b) }
+    }
+}
+
+impl<A, B> Iterator for ChainInfinite<A, B>
+where
+    A: InfiniteIterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.a.size_hint()
+    }
+}
+
+impl<A, B> InfiniteIterator for ChainInfinite<A, B>
+where
+    A: InfiniteIterator,
+    B: Iterator<Item = A::Item>,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        self.a.next_infinite()
+    }
+}
+
+impl<A, B> DoubleEndedIterator for ChainInfinite<A, B>
+where
+    A: InfiniteDoubleEndedIterator,
+    B: DoubleEndedIterator<Item = A::Item>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some(self.next_back_infinite())
+    }
+}
+
+impl<A, B> InfiniteDoubleEndedIterator for ChainInfinite<A, B>
+where
+    A: InfiniteDoubleEndedIterator,
+    B: DoubleEndedIterator<Item = A::Item>,
+{
+    fn next_back_infinite(&mut self) -> Self::Item {
+        if let Some(b) = &mut self.b {
+            if let Some(item) = b.next_back() {
+                return item;
+            }
+            self.b = None;
+        }
+        self.a.next_back_infinite()
+    }
+}
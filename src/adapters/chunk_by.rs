@@ -0,0 +1,71 @@
+use crate::InfiniteIterator;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// An infinite iterator over runs of consecutive items with equal keys.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::chunk_by`](crate::InfiniteIteratorExt::chunk_by).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunkBy<I: Iterator, K, F> {
+    iter: I,
+    key: F,
+    /// The first item of the next run and its key,
+    /// which had to be pulled to find the end of the previous run.
+    pending: Option<(K, I::Item)>,
+}
+
+impl<I: Iterator, K, F> ChunkBy<I, K, F> {
+    pub(crate) fn new(iter: I, key: F) -> Self {
+        Self {
+            iter,
+            key,
+            pending: None,
+        }
+    }
+}
+
+impl<I, K, F> Iterator for ChunkBy<I, K, F>
+where
+    I: InfiniteIterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<I, K, F> InfiniteIterator for ChunkBy<I, K, F>
+where
+    I: InfiniteIterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let (key, first) = match self.pending.take() {
+            Some(pending) => pending,
+            None => {
+                let item = self.iter.next_infinite();
+                ((self.key)(&item), item)
+            }
+        };
+        let mut run = vec![first];
+        loop {
+            let item = self.iter.next_infinite();
+            let item_key = (self.key)(&item);
+            if item_key != key {
+                self.pending = Some((item_key, item));
+                break (key, run);
+            }
+            run.push(item);
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use crate::InfiniteIterator;
+use crate::InfiniteIteratorExt;
+use alloc::vec::Vec;
+
+/// An infinite iterator over consecutive chunks of another iterator's items.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::chunks`](crate::InfiniteIteratorExt::chunks).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Chunks<I> {
+    iter: I,
+    size: usize,
+}
+
+impl<I> Chunks<I> {
+    pub(crate) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self { iter, size }
+    }
+
+    /// Extract the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: InfiniteIterator> Iterator for Chunks<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.size_hint().0 / self.size, None)
+    }
+}
+
+impl<I: InfiniteIterator> InfiniteIterator for Chunks<I> {
+    fn next_infinite(&mut self) -> Self::Item {
+        self.iter.collect_n(self.size)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator that cycles through the elements of an array forever.
+///
+/// Unlike [`cycle_slice`],
+/// this checks at compile time that the array is non-empty.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{cycle_array, InfiniteIterator};
+///
+/// let mut iter = cycle_array(&[1, 2, 3]);
+/// assert_eq!(iter.next_infinite(), &1);
+/// assert_eq!(iter.next_infinite(), &2);
+/// assert_eq!(iter.next_infinite(), &3);
+/// assert_eq!(iter.next_infinite(), &1);
+/// ```
+///
+/// Empty arrays are rejected at compile time:
+///
+/// ```compile_fail
+/// let iter = infinite_iterator::cycle_array::<u32, 0>(&[]);
+/// ```
+pub fn cycle_array<T, const N: usize>(array: &[T; N]) -> CycleSlice<'_, T> {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertNonEmpty::<N>::OK;
+    CycleSlice {
+        slice: array,
+        pos: 0,
+    }
+}
+
+struct AssertNonEmpty<const N: usize>;
+impl<const N: usize> AssertNonEmpty<N> {
+    const OK: () = assert!(N != 0, "cannot cycle an empty array");
+}
+
+/// Create an infinite iterator that cycles through the elements of a slice forever.
+///
+/// Returns [`None`] if the slice is empty,
+/// in which case there would be nothing to cycle through.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{cycle_slice, InfiniteIterator};
+///
+/// let items = vec!['a', 'b'];
+/// let mut iter = cycle_slice(&items).unwrap();
+/// assert_eq!(iter.next_infinite(), &'a');
+/// assert_eq!(iter.next_infinite(), &'b');
+/// assert_eq!(iter.next_infinite(), &'a');
+///
+/// assert!(cycle_slice::<char>(&[]).is_none());
+/// ```
+pub fn cycle_slice<T>(slice: &[T]) -> Option<CycleSlice<'_, T>> {
+    if slice.is_empty() {
+        return None;
+    }
+    Some(CycleSlice { slice, pos: 0 })
+}
+
+/// An infinite iterator that cycles through the elements of a non-empty slice.
+///
+/// This `struct` is created by [`cycle_array`] and [`cycle_slice`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct CycleSlice<'a, T> {
+    /// Never empty.
+    slice: &'a [T],
+    /// Always in bounds of `slice`.
+    pos: usize,
+}
+
+impl<T> Clone for CycleSlice<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, T> Iterator for CycleSlice<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T> InfiniteIterator for CycleSlice<'_, T> {
+    fn next_infinite(&mut self) -> Self::Item {
+        let item = &self.slice[self.pos];
+        self.pos += 1;
+        if self.pos == self.slice.len() {
+            self.pos = 0;
+        }
+        item
+    }
+}
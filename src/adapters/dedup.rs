@@ -0,0 +1,68 @@
+use crate::InfiniteIterator;
+
+/// An infinite iterator that removes consecutive duplicate items.
+///
+/// This type is created by
+/// [`InfiniteIteratorExt::dedup`](crate::InfiniteIteratorExt::dedup).
+pub type Dedup<I> = DedupBy<I, fn(&<I as Iterator>::Item, &<I as Iterator>::Item) -> bool>;
+
+/// An infinite iterator that removes consecutive items
+/// considered duplicates by a function.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::dedup_by`](crate::InfiniteIteratorExt::dedup_by).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DedupBy<I: Iterator, F> {
+    iter: I,
+    same_bucket: F,
+    /// The next item to yield,
+    /// which had to be pulled to find the end of the previous run.
+    pending: Option<I::Item>,
+}
+
+impl<I: Iterator, F> DedupBy<I, F> {
+    pub(crate) fn new(iter: I, same_bucket: F) -> Self {
+        Self {
+            iter,
+            same_bucket,
+            pending: None,
+        }
+    }
+}
+
+impl<I, F> Iterator for DedupBy<I, F>
+where
+    I: InfiniteIterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<I, F> InfiniteIterator for DedupBy<I, F>
+where
+    I: InfiniteIterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let current = match self.pending.take() {
+            Some(item) => item,
+            None => self.iter.next_infinite(),
+        };
+        loop {
+            let item = self.iter.next_infinite();
+            if !(self.same_bucket)(&current, &item) {
+                self.pending = Some(item);
+                break current;
+            }
+        }
+    }
+}
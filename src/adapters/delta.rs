@@ -0,0 +1,121 @@
+use crate::overflow::{Integer, Policy};
+use crate::InfiniteIterator;
+
+/// An infinite iterator over the differences between
+/// consecutive items of another iterator.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::delta_encode`](crate::InfiniteIteratorExt::delta_encode)
+/// and [`InfiniteIteratorExt::delta_encode_from`](crate::InfiniteIteratorExt::delta_encode_from).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DeltaEncode<I: Iterator, P> {
+    iter: I,
+    /// The item the next item will be compared against,
+    /// or `None` if the next item is to be yielded as-is.
+    prev: Option<I::Item>,
+    /// Whether a difference has overflowed,
+    /// after which every item is `None`.
+    overflowed: bool,
+    policy: P,
+}
+
+impl<I: Iterator, P> DeltaEncode<I, P> {
+    pub(crate) fn new(iter: I, baseline: Option<I::Item>, policy: P) -> Self {
+        Self {
+            iter,
+            prev: baseline,
+            overflowed: false,
+            policy,
+        }
+    }
+}
+
+impl<I, P> Iterator for DeltaEncode<I, P>
+where
+    I: InfiniteIterator,
+    I::Item: Integer,
+    P: Policy,
+{
+    type Item = P::Output<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, P> InfiniteIterator for DeltaEncode<I, P>
+where
+    I: InfiniteIterator,
+    I::Item: Integer,
+    P: Policy,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let item = self.iter.next_infinite();
+        let policy = self.policy;
+        let delta = match self.prev.replace(item) {
+            Some(prev) => policy.sub(item, prev),
+            None => Some(item),
+        };
+        self.overflowed |= delta.is_none();
+        policy.output(delta.filter(|_| !self.overflowed))
+    }
+}
+
+/// An infinite iterator over the running sum of another iterator's items.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::delta_decode`](crate::InfiniteIteratorExt::delta_decode).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DeltaDecode<I: Iterator, P> {
+    iter: I,
+    /// The most recent sum, or `None` if it has overflowed.
+    sum: Option<I::Item>,
+    policy: P,
+}
+
+impl<I: Iterator, P> DeltaDecode<I, P> {
+    pub(crate) fn new(iter: I, initial: I::Item, policy: P) -> Self {
+        Self {
+            iter,
+            sum: Some(initial),
+            policy,
+        }
+    }
+}
+
+impl<I, P> Iterator for DeltaDecode<I, P>
+where
+    I: InfiniteIterator,
+    I::Item: Integer,
+    P: Policy,
+{
+    type Item = P::Output<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, P> InfiniteIterator for DeltaDecode<I, P>
+where
+    I: InfiniteIterator,
+    I::Item: Integer,
+    P: Policy,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let delta = self.iter.next_infinite();
+        let policy = self.policy;
+        self.sum = self.sum.and_then(|sum| policy.add(sum, delta));
+        policy.output(self.sum)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::InfiniteIterator;
+use crate::Memoized;
+use core::fmt::{self, Debug, Formatter};
+
+/// An infinite iterator over every pair of items of two infinite iterators,
+/// ordered by anti-diagonals.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::diagonal_product`](crate::InfiniteIteratorExt::diagonal_product).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DiagonalProduct<A: Iterator, B: Iterator> {
+    a: Memoized<A>,
+    b: Memoized<B>,
+    /// The sum of the indices of the pairs on the current anti-diagonal.
+    diagonal: usize,
+    /// The index into `a` of the next pair; always at most `diagonal`.
+    i: usize,
+}
+
+impl<A: Iterator, B: Iterator> DiagonalProduct<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self {
+            a: Memoized::new(a),
+            b: Memoized::new(b),
+            diagonal: 0,
+            i: 0,
+        }
+    }
+}
+
+impl<A, B> Clone for DiagonalProduct<A, B>
+where
+    A: Iterator + Clone,
+    B: Iterator + Clone,
+    A::Item: Clone,
+    B::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            diagonal: self.diagonal,
+            i: self.i,
+        }
+    }
+}
+
+impl<A, B> Debug for DiagonalProduct<A, B>
+where
+    A: Iterator + Debug,
+    B: Iterator + Debug,
+    A::Item: Debug,
+    B::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DiagonalProduct")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .field("diagonal", &self.diagonal)
+            .field("i", &self.i)
+            .finish()
+    }
+}
+
+impl<A, B> Iterator for DiagonalProduct<A, B>
+where
+    A: InfiniteIterator,
+    B: InfiniteIterator,
+    A::Item: Clone,
+    B::Item: Clone,
+{
+    type Item = (A::Item, B::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<A, B> InfiniteIterator for DiagonalProduct<A, B>
+where
+    A: InfiniteIterator,
+    B: InfiniteIterator,
+    A::Item: Clone,
+    B::Item: Clone,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let pair = (
+            self.a.get(self.i).clone(),
+            self.b.get(self.diagonal - self.i).clone(),
+        );
+        if self.i == self.diagonal {
+            self.diagonal += 1;
+            self.i = 0;
+        } else {
+            self.i += 1;
+        }
+        pair
+    }
+}
@@ -0,0 +1,59 @@
+use crate::InfiniteIterator;
+
+/// An infinite iterator that calls a closure on every `n`th item
+/// before yielding it.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::inspect_every`](crate::InfiniteIteratorExt::inspect_every).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct InspectEvery<I, F> {
+    iter: I,
+    f: F,
+    n: usize,
+    /// The index of the next item.
+    index: usize,
+}
+
+impl<I, F> InspectEvery<I, F> {
+    pub(crate) fn new(iter: I, n: usize, f: F) -> Self {
+        assert!(n != 0, "`inspect_every` interval must be non-zero");
+        Self {
+            iter,
+            f,
+            n,
+            index: 0,
+        }
+    }
+}
+
+impl<I, F> Iterator for InspectEvery<I, F>
+where
+    I: InfiniteIterator,
+    F: FnMut(usize, &I::Item),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> InfiniteIterator for InspectEvery<I, F>
+where
+    I: InfiniteIterator,
+    F: FnMut(usize, &I::Item),
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let item = self.iter.next_infinite();
+        if self.index % self.n == 0 {
+            (self.f)(self.index, &item);
+        }
+        self.index = self.index.wrapping_add(1);
+        item
+    }
+}
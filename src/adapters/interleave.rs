@@ -0,0 +1,62 @@
+use crate::InfiniteIterator;
+
+/// An infinite iterator that alternates between the items of two iterators.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::interleave`](crate::InfiniteIteratorExt::interleave).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Interleave<A, B> {
+    a: A,
+    /// The second iterator, or `None` once it has ended.
+    b: Option<B>,
+    /// Whether the next item is to be taken from `b`.
+    next_from_b: bool,
+}
+
+impl<A, B> Interleave<A, B> {
+    pub(crate) fn new(a: A, b: B) -> Self {
+        Self {
+            a,
+            b: Some(b),
+            next_from_b: false,
+        }
+    }
+}
+
+impl<A, B> Iterator for Interleave<A, B>
+where
+    A: InfiniteIterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let b = self.b.as_ref().map_or(0, |b| b.size_hint().0);
+        (self.a.size_hint().0.saturating_add(b), None)
+    }
+}
+
+impl<A, B> InfiniteIterator for Interleave<A, B>
+where
+    A: InfiniteIterator,
+    B: Iterator<Item = A::Item>,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let from_b = self.next_from_b;
+        self.next_from_b = !from_b;
+        if from_b {
+            if let Some(b) = &mut self.b {
+                match b.next() {
+                    Some(item) => return item,
+                    None => self.b = None,
+                }
+            }
+        }
+        self.a.next_infinite()
+    }
+}
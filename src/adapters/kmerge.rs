@@ -0,0 +1,181 @@
+use crate::InfiniteIterator;
+use crate::IntoInfiniteIterator;
+use alloc::collections::BinaryHeap;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter};
+
+/// Merge any number of infinite iterators, each in ascending order,
+/// into a single infinite iterator in ascending order.
+///
+/// When items from several iterators are equal,
+/// they are yielded in the order their iterators were given.
+///
+/// Returns [`None`] if there are no iterators,
+/// in which case there would be nothing to yield.
+///
+/// # Examples
+///
+/// Combining per-shard monotone feeds:
+///
+/// ```
+/// use infinite_iterator::{kmerge, InfiniteIterator, InfiniteIteratorExt};
+///
+/// let shards = [(0..).step_by(3), (1..).step_by(5), (2..).step_by(4)];
+/// let mut merged = kmerge(shards).unwrap();
+/// assert_eq!(merged.next_array(), [0, 1, 2, 3, 6, 6, 6, 9, 10, 11]);
+///
+/// assert!(kmerge(Vec::<core::ops::RangeFrom<u8>>::new()).is_none());
+/// ```
+pub fn kmerge<C>(
+    iterators: C,
+) -> Option<KMerge<<C::Item as IntoInfiniteIterator>::IntoInfiniteIter>>
+where
+    C: IntoIterator,
+    C::Item: IntoInfiniteIterator,
+    <C::Item as IntoInfiniteIterator>::Item: Ord,
+{
+    let heap: BinaryHeap<_> = iterators
+        .into_iter()
+        .enumerate()
+        .map(|(index, iter)| {
+            let mut iter = iter.into_infinite_iter();
+            Head {
+                item: iter.next_infinite(),
+                index,
+                iter,
+            }
+        })
+        .collect();
+    if heap.is_empty() {
+        return None;
+    }
+    Some(KMerge { heap })
+}
+
+/// An infinite iterator that merges several ascending infinite iterators
+/// into one ascending infinite iterator.
+///
+/// This `struct` is created by [`kmerge`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct KMerge<I: Iterator> {
+    /// The next item of each iterator; never empty.
+    heap: BinaryHeap<Head<I>>,
+}
+
+/// An iterator along with its next item.
+struct Head<I: Iterator> {
+    item: I::Item,
+    /// The position of the iterator among those passed to [`kmerge`],
+    /// used to break ties.
+    index: usize,
+    iter: I,
+}
+
+impl<I: Iterator> PartialEq for Head<I>
+where
+    I::Item: Ord,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<I: Iterator> Eq for Head<I> where I::Item: Ord {}
+
+impl<I: Iterator> PartialOrd for Head<I>
+where
+    I::Item: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I: Iterator> Ord for Head<I>
+where
+    I::Item: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap, so reverse the order
+        // to put the smallest item first.
+        (&other.item, other.index).cmp(&(&self.item, self.index))
+    }
+}
+
+impl<I> Clone for KMerge<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            heap: self.heap.clone(),
+        }
+    }
+}
+
+impl<I> Debug for KMerge<I>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KMerge").field("heap", &self.heap).finish()
+    }
+}
+
+impl<I> Clone for Head<I>
+where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            item: self.item.clone(),
+            index: self.index,
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<I> Debug for Head<I>
+where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Head")
+            .field("item", &self.item)
+            .field("index", &self.index)
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I> Iterator for KMerge<I>
+where
+    I: InfiniteIterator,
+    I::Item: Ord,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I> InfiniteIterator for KMerge<I>
+where
+    I: InfiniteIterator,
+    I::Item: Ord,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let mut head = self.heap.peek_mut().unwrap();
+        let next = head.iter.next_infinite();
+        core::mem::replace(&mut head.item, next)
+    }
+}
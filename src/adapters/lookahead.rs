@@ -0,0 +1,100 @@
+use crate::InfiniteIterator;
+use core::array;
+use core::mem;
+
+/// An infinite iterator that allows peeking at
+/// a fixed number of upcoming items.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::lookahead`](crate::InfiniteIteratorExt::lookahead).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Lookahead<I: Iterator, const N: usize> {
+    iter: I,
+    /// A ring buffer of the next `N` items.
+    buf: [I::Item; N],
+    /// The index in `buf` of the next item; always less than `N` unless `N` is zero.
+    head: usize,
+}
+
+impl<I: InfiniteIterator, const N: usize> Lookahead<I, N> {
+    pub(crate) fn new(mut iter: I) -> Self {
+        let buf = array::from_fn(|_| iter.next_infinite());
+        Self { iter, buf, head: 0 }
+    }
+
+    /// Get a reference to the item `k` positions ahead,
+    /// where `peek_ahead(0)` is the next item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).lookahead::<3>();
+    /// assert_eq!(*iter.peek_ahead(0), 0);
+    /// assert_eq!(*iter.peek_ahead(2), 2);
+    /// assert_eq!(iter.next_infinite(), 0);
+    /// assert_eq!(*iter.peek_ahead(2), 3);
+    /// ```
+    pub fn peek_ahead(&self, k: usize) -> &I::Item {
+        &self.buf[self.index(k)]
+    }
+
+    /// Get a unique reference to the item `k` positions ahead,
+    /// where `peek_ahead_mut(0)` is the next item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not less than `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).lookahead::<2>();
+    /// *iter.peek_ahead_mut(1) = 10;
+    /// assert_eq!(iter.next_infinite(), 0);
+    /// assert_eq!(iter.next_infinite(), 10);
+    /// ```
+    pub fn peek_ahead_mut(&mut self, k: usize) -> &mut I::Item {
+        &mut self.buf[self.index(k)]
+    }
+
+    fn index(&self, k: usize) -> usize {
+        assert!(
+            k < N,
+            "lookahead index {k} out of range for buffer of size {N}"
+        );
+        (self.head + k) % N
+    }
+}
+
+impl<I: InfiniteIterator, const N: usize> Iterator for Lookahead<I, N> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.size_hint().0.saturating_add(N), None)
+    }
+}
+
+impl<I: InfiniteIterator, const N: usize> InfiniteIterator for Lookahead<I, N> {
+    fn next_infinite(&mut self) -> Self::Item {
+        let next = self.iter.next_infinite();
+        if N == 0 {
+            return next;
+        }
+        let item = mem::replace(&mut self.buf[self.head], next);
+        self.head = (self.head + 1) % N;
+        item
+    }
+}
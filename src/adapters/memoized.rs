@@ -0,0 +1,158 @@
+use crate::InfiniteIterator;
+use alloc::vec::Vec;
+use core::ops::Index;
+
+/// A lazily-materialized infinite sequence backed by an infinite iterator.
+///
+/// Every item produced by the underlying iterator is cached,
+/// so it can be accessed again by index in constant time.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::memoize`](crate::InfiniteIteratorExt::memoize).
+#[derive(Debug, Clone)]
+pub struct Memoized<I: Iterator> {
+    iter: I,
+    cache: Vec<I::Item>,
+}
+
+impl<I: Iterator> Memoized<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            cache: Vec::new(),
+        }
+    }
+
+    /// Get the items that have been produced so far.
+    pub fn cached(&self) -> &[I::Item] {
+        &self.cache
+    }
+
+    /// Extract the items that have been produced so far.
+    pub fn into_cached(self) -> Vec<I::Item> {
+        self.cache
+    }
+}
+
+impl<I: InfiniteIterator> Memoized<I> {
+    /// Get the item at index `n`,
+    /// pulling items from the underlying iterator as necessary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let mut squares = (0_u64..).map(|n| n * n).memoize();
+    /// assert_eq!(*squares.get(10), 100);
+    /// assert_eq!(*squares.get(3), 9);
+    /// assert_eq!(squares.cached().len(), 11);
+    /// ```
+    pub fn get(&mut self, n: usize) -> &I::Item {
+        self.materialize(n);
+        &self.cache[n]
+    }
+
+    /// Get a unique reference to the item at index `n`,
+    /// pulling items from the underlying iterator as necessary.
+    pub fn get_mut(&mut self, n: usize) -> &mut I::Item {
+        self.materialize(n);
+        &mut self.cache[n]
+    }
+
+    /// Find the index of the first item satisfying `pred`,
+    /// given an iterator partitioned such that
+    /// every item satisfying `pred` comes after every item that does not.
+    ///
+    /// This is like [`slice::partition_point`],
+    /// except the predicate is inverted
+    /// (it returns `true` for the items _after_ the partition point)
+    /// and the search is unbounded:
+    /// `pred` is first tested on exponentially increasing indices
+    /// until it returns `true`,
+    /// and then a binary search is done on the last interval.
+    /// This calls `pred` `O(log n)` times,
+    /// where `n` is the returned index.
+    /// If `pred` never returns `true`,
+    /// this method loops forever.
+    ///
+    /// # Examples
+    ///
+    /// Finding the first square greater than a million:
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let mut squares = (0_u64..).map(|n| n * n).memoize();
+    /// let index = squares.partition_point(|&square| square > 1_000_000);
+    /// assert_eq!(index, 1001);
+    /// assert_eq!(squares[index], 1_002_001);
+    /// ```
+    pub fn partition_point<P>(&mut self, mut pred: P) -> usize
+    where
+        P: FnMut(&I::Item) -> bool,
+    {
+        // Every item before `lo` does not satisfy the predicate.
+        let mut lo = 0;
+        let mut hi = 0;
+        while !pred(self.get(hi)) {
+            lo = hi + 1;
+            hi = hi * 2 + 1;
+        }
+        lo + self.cache[lo..hi].partition_point(|item| !pred(item))
+    }
+
+    /// Find the first item greater than or equal to `value`
+    /// in an iterator whose items are in ascending order,
+    /// returning its index and a reference to it.
+    ///
+    /// This uses the same exponential search as
+    /// [`partition_point`](Self::partition_point).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let mut evens = (0..).step_by(2).memoize();
+    /// assert_eq!(evens.find_sorted(&7), (4, &8));
+    /// assert_eq!(evens.find_sorted(&8), (4, &8));
+    /// ```
+    pub fn find_sorted(&mut self, value: &I::Item) -> (usize, &I::Item)
+    where
+        I::Item: Ord,
+    {
+        let index = self.partition_point(|item| item >= value);
+        (index, &self.cache[index])
+    }
+
+    /// Ensure that the items up to and including index `n` are cached.
+    pub fn materialize(&mut self, n: usize) {
+        if let Some(needed) = (n + 1).checked_sub(self.cache.len()) {
+            self.cache.reserve(needed);
+            for _ in 0..needed {
+                self.cache.push(self.iter.next_infinite());
+            }
+        }
+    }
+}
+
+/// Access an item that has already been produced.
+///
+/// # Panics
+///
+/// Panics if the item at the index has not been produced yet;
+/// use [`Memoized::get`] or [`Memoized::materialize`] to produce it first.
+impl<I: Iterator> Index<usize> for Memoized<I> {
+    type Output = I::Item;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.cache.get(index) {
+            Some(item) => item,
+            None => panic!(
+                "index {index} has not been materialized yet (only {} items are cached)",
+                self.cache.len()
+            ),
+        }
+    }
+}
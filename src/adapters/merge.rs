@@ -0,0 +1,95 @@
+use crate::InfiniteIterator;
+use crate::InfinitePeekable;
+use core::fmt::{self, Debug, Formatter};
+
+/// An infinite iterator that merges two ascending infinite iterators
+/// into one ascending infinite iterator.
+///
+/// This type is created by
+/// [`InfiniteIteratorExt::merge`](crate::InfiniteIteratorExt::merge).
+pub type Merge<A, B> = MergeBy<A, B, fn(&<A as Iterator>::Item, &<A as Iterator>::Item) -> bool>;
+
+/// An infinite iterator that merges two infinite iterators
+/// according to a function deciding which item comes first.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::merge_by`](crate::InfiniteIteratorExt::merge_by).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MergeBy<A: Iterator, B: Iterator, F> {
+    a: InfinitePeekable<A>,
+    b: InfinitePeekable<B>,
+    is_first: F,
+}
+
+impl<A: Iterator, B: Iterator, F> MergeBy<A, B, F> {
+    pub(crate) fn new(a: A, b: B, is_first: F) -> Self {
+        Self {
+            a: InfinitePeekable::new(a),
+            b: InfinitePeekable::new(b),
+            is_first,
+        }
+    }
+}
+
+impl<A, B, F> Clone for MergeBy<A, B, F>
+where
+    A: Iterator + Clone,
+    B: Iterator<Item = A::Item> + Clone,
+    A::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            a: self.a.clone(),
+            b: self.b.clone(),
+            is_first: self.is_first.clone(),
+        }
+    }
+}
+
+impl<A, B, F> Debug for MergeBy<A, B, F>
+where
+    A: Iterator + Debug,
+    B: Iterator<Item = A::Item> + Debug,
+    A::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MergeBy")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A, B, F> Iterator for MergeBy<A, B, F>
+where
+    A: InfiniteIterator,
+    B: InfiniteIterator<Item = A::Item>,
+    F: FnMut(&A::Item, &A::Item) -> bool,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = self.a.size_hint().0.saturating_add(self.b.size_hint().0);
+        (lower, None)
+    }
+}
+
+impl<A, B, F> InfiniteIterator for MergeBy<A, B, F>
+where
+    A: InfiniteIterator,
+    B: InfiniteIterator<Item = A::Item>,
+    F: FnMut(&A::Item, &A::Item) -> bool,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        if (self.is_first)(self.a.peek(), self.b.peek()) {
+            self.a.next_infinite()
+        } else {
+            self.b.next_infinite()
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::InfiniteIterator;
+use alloc::collections::VecDeque;
+
+/// An infinite iterator that allows peeking arbitrarily far ahead.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::multipeek`](crate::InfiniteIteratorExt::multipeek).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MultiPeek<I: Iterator> {
+    iter: I,
+    buf: VecDeque<I::Item>,
+    /// The index in `buf` of the item the next peek will return.
+    cursor: usize,
+}
+
+impl<I: Iterator> MultiPeek<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            buf: VecDeque::new(),
+            cursor: 0,
+        }
+    }
+
+    /// Reset the peek cursor,
+    /// so that the next call to [`peek_infinite`](Self::peek_infinite)
+    /// returns the next item of the iterator.
+    pub fn reset_peek(&mut self) {
+        self.cursor = 0;
+    }
+}
+
+impl<I: InfiniteIterator> MultiPeek<I> {
+    /// Get a reference to the item at the peek cursor,
+    /// then advance the peek cursor.
+    ///
+    /// The peek cursor starts at the next item of the iterator,
+    /// and is reset when the iterator is advanced
+    /// or [`reset_peek`](Self::reset_peek) is called.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).multipeek();
+    /// assert_eq!(*iter.peek_infinite(), 0);
+    /// assert_eq!(*iter.peek_infinite(), 1);
+    /// assert_eq!(*iter.peek_infinite(), 2);
+    ///
+    /// iter.reset_peek();
+    /// assert_eq!(*iter.peek_infinite(), 0);
+    ///
+    /// assert_eq!(iter.next_infinite(), 0);
+    /// assert_eq!(*iter.peek_infinite(), 1);
+    /// ```
+    pub fn peek_infinite(&mut self) -> &I::Item {
+        if self.cursor == self.buf.len() {
+            self.buf.push_back(self.iter.next_infinite());
+        }
+        let item = &self.buf[self.cursor];
+        self.cursor += 1;
+        item
+    }
+}
+
+impl<I: InfiniteIterator> Iterator for MultiPeek<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.size_hint().0.saturating_add(self.buf.len()), None)
+    }
+}
+
+impl<I: InfiniteIterator> InfiniteIterator for MultiPeek<I> {
+    fn next_infinite(&mut self) -> Self::Item {
+        self.cursor = 0;
+        match self.buf.pop_front() {
+            Some(item) => item,
+            None => self.iter.next_infinite(),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::InfiniteIterator;
+use alloc::vec::Vec;
+
+/// Create an infinite iterator that yields the items of a finite iterator
+/// and then cycles through them forever,
+/// without requiring the iterator to be [`Clone`].
+///
+/// This is the free-function form of
+/// [`IteratorExt::or_cycle`](crate::IteratorExt::or_cycle).
+/// Returns [`None`] if the iterator is empty,
+/// in which case there would be nothing to cycle through.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{cycle_buffer, CycleBuffer, InfiniteIteratorExt};
+///
+/// // Not `Clone`, since the closure owns a `Vec`.
+/// let suffixes = vec!["st", "nd", "rd"];
+/// let source = (0..3).map(move |i| suffixes[i]);
+///
+/// let mut iter: CycleBuffer<_> = cycle_buffer(source).unwrap();
+/// assert_eq!(iter.next_array(), ["st", "nd", "rd", "st", "nd"]);
+///
+/// assert!(cycle_buffer(Vec::<u32>::new()).is_none());
+/// ```
+pub fn cycle_buffer<I>(iter: I) -> Option<CycleBuffer<I::IntoIter>>
+where
+    I: IntoIterator,
+    I::Item: Clone,
+{
+    OrCycle::new(iter.into_iter())
+}
+
+/// An iterator that records the items of a finite iterator into a buffer
+/// and then cycles through them forever.
+///
+/// This is another name for [`OrCycle`].
+/// It is created by [`cycle_buffer`].
+pub type CycleBuffer<I> = OrCycle<I>;
+
+/// An iterator that yields the items of a finite iterator
+/// and then cycles through them forever.
+///
+/// Unlike [`iter::Cycle`](core::iter::Cycle),
+/// this does not require the underlying iterator to be [`Clone`],
+/// because items are recorded into a buffer on the first pass.
+///
+/// This `struct` is created by [`IteratorExt::or_cycle`](crate::IteratorExt::or_cycle)
+/// and [`cycle_buffer`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OrCycle<I: Iterator> {
+    /// The source iterator, or `None` once it has ended.
+    iter: Option<I>,
+    /// Every item yielded by the source iterator so far; never empty.
+    buf: Vec<I::Item>,
+    /// The index in `buf` of the next item to yield.
+    pos: usize,
+}
+
+impl<I: Iterator> OrCycle<I> {
+    pub(crate) fn new(mut iter: I) -> Option<Self> {
+        let first = iter.next()?;
+        Some(Self {
+            iter: Some(iter),
+            buf: alloc::vec![first],
+            pos: 0,
+        })
+    }
+
+    /// Get the items recorded from the underlying iterator so far.
+    ///
+    /// Once the underlying iterator has ended,
+    /// this is exactly the sequence of items being cycled through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, IteratorExt};
+    ///
+    /// // This iterator is not `Clone`, since its closure owns a `Mutex`.
+    /// let offset = std::sync::Mutex::new(10);
+    /// let source = (0..3).map(move |num| num + *offset.lock().unwrap());
+    /// let mut iter = source.or_cycle().unwrap();
+    /// assert_eq!(iter.buffer(), [10]);
+    ///
+    /// iter.nth(3);
+    /// assert_eq!(iter.buffer(), [10, 11, 12]);
+    /// assert_eq!(iter.next_infinite(), 11);
+    /// ```
+    pub fn buffer(&self) -> &[I::Item] {
+        &self.buf
+    }
+}
+
+impl<I: Iterator> Iterator for OrCycle<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I: Iterator> InfiniteIterator for OrCycle<I>
+where
+    I::Item: Clone,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        if let Some(iter) = &mut self.iter {
+            // The first item is pulled eagerly in `new`.
+            if self.pos < self.buf.len() {
+                self.pos += 1;
+                return self.buf[self.pos - 1].clone();
+            }
+            if let Some(item) = iter.next() {
+                self.buf.push(item.clone());
+                self.pos += 1;
+                return item;
+            }
+            self.iter = None;
+            self.pos = 0;
+        }
+        let item = self.buf[self.pos].clone();
+        self.pos = (self.pos + 1) % self.buf.len();
+        item
+    }
+}
@@ -0,0 +1,51 @@
+use crate::InfiniteIterator;
+use core::iter;
+
+/// An iterator that yields the items of a finite iterator
+/// and then repeats a value forever.
+///
+/// This `struct` is created by [`IteratorExt::or_repeat`](crate::IteratorExt::or_repeat).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OrRepeat<I, T> {
+    iter: iter::Fuse<I>,
+    value: T,
+}
+
+impl<I: Iterator, T> OrRepeat<I, T> {
+    pub(crate) fn new(iter: I, value: T) -> Self {
+        Self {
+            iter: iter.fuse(),
+            value,
+        }
+    }
+}
+
+impl<I, T> Iterator for OrRepeat<I, T>
+where
+    I: Iterator<Item = T>,
+    T: Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I, T> InfiniteIterator for OrRepeat<I, T>
+where
+    I: Iterator<Item = T>,
+    T: Clone,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        match self.iter.next() {
+            Some(item) => item,
+            None => self.value.clone(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::InfiniteIterator;
+use core::iter;
+
+/// An iterator that yields the items of a finite iterator
+/// and then calls a closure forever to produce more items.
+///
+/// This `struct` is created by
+/// [`IteratorExt::or_repeat_with`](crate::IteratorExt::or_repeat_with).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OrRepeatWith<I, F> {
+    iter: iter::Fuse<I>,
+    f: F,
+}
+
+impl<I: Iterator, F> OrRepeatWith<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: iter.fuse(),
+            f,
+        }
+    }
+}
+
+impl<I, F> Iterator for OrRepeatWith<I, F>
+where
+    I: Iterator,
+    F: FnMut() -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I, F> InfiniteIterator for OrRepeatWith<I, F>
+where
+    I: Iterator,
+    F: FnMut() -> I::Item,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        match self.iter.next() {
+            Some(item) => item,
+            None => (self.f)(),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use crate::InfiniteDoubleEndedIterator;
+use crate::InfiniteIterator;
+
+/// An infinite iterator with a `peek()` that returns a reference to the next item.
+///
+/// Unlike [`iter::Peekable`](core::iter::Peekable),
+/// which has to account for the underlying iterator ending,
+/// this only stores an `Option<Item>`
+/// and its peeking methods return references directly.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::peekable_infinite`](crate::InfiniteIteratorExt::peekable_infinite).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct InfinitePeekable<I: Iterator> {
+    iter: I,
+    peeked: Option<I::Item>,
+}
+
+impl<I: Iterator> InfinitePeekable<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter, peeked: None }
+    }
+}
+
+impl<I: InfiniteIterator> InfinitePeekable<I> {
+    /// Get a reference to the next item without advancing the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).peekable_infinite();
+    /// assert_eq!(*iter.peek(), 0);
+    /// assert_eq!(*iter.peek(), 0);
+    /// assert_eq!(iter.next_infinite(), 0);
+    /// assert_eq!(*iter.peek(), 1);
+    /// ```
+    pub fn peek(&mut self) -> &I::Item {
+        self.peeked.get_or_insert_with(|| self.iter.next_infinite())
+    }
+
+    /// Get a unique reference to the next item without advancing the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).peekable_infinite();
+    /// *iter.peek_mut() = 10;
+    /// assert_eq!(iter.next_infinite(), 10);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// ```
+    pub fn peek_mut(&mut self) -> &mut I::Item {
+        self.peeked.get_or_insert_with(|| self.iter.next_infinite())
+    }
+
+    /// Consume and return the next item if it satisfies the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).peekable_infinite();
+    /// assert_eq!(iter.next_if(|&num| num == 0), Some(0));
+    /// assert_eq!(iter.next_if(|&num| num == 0), None);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// ```
+    pub fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        let item = self.next_infinite();
+        if func(&item) {
+            Some(item)
+        } else {
+            self.peeked = Some(item);
+            None
+        }
+    }
+
+    /// Consume and return the next item if it is equal to `expected`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).peekable_infinite();
+    /// assert_eq!(iter.next_if_eq(&0), Some(0));
+    /// assert_eq!(iter.next_if_eq(&0), None);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// ```
+    pub fn next_if_eq<T>(&mut self, expected: &T) -> Option<I::Item>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.next_if(|item| item == expected)
+    }
+}
+
+impl<I: InfiniteIterator> Iterator for InfinitePeekable<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let peeked = usize::from(self.peeked.is_some());
+        (self.iter.size_hint().0.saturating_add(peeked), None)
+    }
+}
+
+impl<I: InfiniteIterator> InfiniteIterator for InfinitePeekable<I> {
+    fn next_infinite(&mut self) -> Self::Item {
+        match self.peeked.take() {
+            Some(item) => item,
+            None => self.iter.next_infinite(),
+        }
+    }
+}
+
+impl<I: InfiniteDoubleEndedIterator> DoubleEndedIterator for InfinitePeekable<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some(self.next_back_infinite())
+    }
+}
+
+impl<I: InfiniteDoubleEndedIterator> InfiniteDoubleEndedIterator for InfinitePeekable<I> {
+    fn next_back_infinite(&mut self) -> Self::Item {
+        self.iter.next_back_infinite()
+    }
+}
@@ -0,0 +1,153 @@
+use crate::InfiniteIterator;
+use crate::IntoInfiniteIterator;
+
+/// Create an infinite iterator that allows putting back a single item.
+///
+/// # Examples
+///
+/// A tokenizer that sometimes reads one character too many:
+///
+/// ```
+/// use infinite_iterator::{cycle_array, put_back, InfiniteIterator};
+///
+/// let mut chars = put_back(cycle_array(&['1', '2', ' ']).copied());
+/// let mut number = String::new();
+/// loop {
+///     let c = chars.next_infinite();
+///     if !c.is_ascii_digit() {
+///         chars.put_back(c);
+///         break;
+///     }
+///     number.push(c);
+/// }
+/// assert_eq!(number, "12");
+/// assert_eq!(chars.next_infinite(), ' ');
+/// ```
+pub fn put_back<I: IntoInfiniteIterator>(iter: I) -> PutBack<I::IntoInfiniteIter> {
+    PutBack {
+        top: None,
+        iter: iter.into_infinite_iter(),
+    }
+}
+
+/// An infinite iterator that allows putting back a single item.
+///
+/// This `struct` is created by [`put_back()`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PutBack<I: Iterator> {
+    top: Option<I::Item>,
+    iter: I,
+}
+
+impl<I: Iterator> PutBack<I> {
+    /// Put back a single item,
+    /// so that it is the next item yielded by the iterator.
+    ///
+    /// If an item was already put back, it is replaced and returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIterator;
+    ///
+    /// let mut iter = infinite_iterator::put_back(1..);
+    /// let first = iter.next_infinite();
+    /// assert_eq!(iter.put_back(first), None);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// assert_eq!(iter.next_infinite(), 2);
+    /// ```
+    pub fn put_back(&mut self, item: I::Item) -> Option<I::Item> {
+        self.top.replace(item)
+    }
+}
+
+impl<I: InfiniteIterator> Iterator for PutBack<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let put_back = usize::from(self.top.is_some());
+        (self.iter.size_hint().0.saturating_add(put_back), None)
+    }
+}
+
+impl<I: InfiniteIterator> InfiniteIterator for PutBack<I> {
+    fn next_infinite(&mut self) -> Self::Item {
+        match self.top.take() {
+            Some(item) => item,
+            None => self.iter.next_infinite(),
+        }
+    }
+}
+
+/// Create an infinite iterator that allows putting back any number of items.
+///
+/// See [`PutBackN::put_back`].
+#[cfg(feature = "alloc")]
+pub fn put_back_n<I: IntoInfiniteIterator>(iter: I) -> PutBackN<I::IntoInfiniteIter> {
+    PutBackN {
+        top: alloc::vec::Vec::new(),
+        iter: iter.into_infinite_iter(),
+    }
+}
+
+/// An infinite iterator that allows putting back any number of items.
+///
+/// This `struct` is created by [`put_back_n()`].
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PutBackN<I: Iterator> {
+    /// A stack of put back items; the last one is yielded first.
+    top: alloc::vec::Vec<I::Item>,
+    iter: I,
+}
+
+#[cfg(feature = "alloc")]
+impl<I: Iterator> PutBackN<I> {
+    /// Put back an item,
+    /// so that it is the next item yielded by the iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIterator;
+    ///
+    /// let mut iter = infinite_iterator::put_back_n(3..);
+    /// iter.put_back(2);
+    /// iter.put_back(1);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// assert_eq!(iter.next_infinite(), 2);
+    /// assert_eq!(iter.next_infinite(), 3);
+    /// ```
+    pub fn put_back(&mut self, item: I::Item) {
+        self.top.push(item);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: InfiniteIterator> Iterator for PutBackN<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.size_hint().0.saturating_add(self.top.len()), None)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<I: InfiniteIterator> InfiniteIterator for PutBackN<I> {
+    fn next_infinite(&mut self) -> Self::Item {
+        match self.top.pop() {
+            Some(item) => item,
+            None => self.iter.next_infinite(),
+        }
+    }
+}
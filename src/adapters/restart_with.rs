@@ -0,0 +1,81 @@
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator that,
+/// whenever its current iterator ends,
+/// calls a closure to create a new one.
+///
+/// The closure is first called when the first item is requested.
+/// The closure must return a non-empty iterator infinitely often,
+/// otherwise the resulting iterator will loop forever
+/// looking for the next item.
+///
+/// # Examples
+///
+/// Reconnecting to a source whenever it is exhausted:
+///
+/// ```
+/// use infinite_iterator::{restart_with, InfiniteIterator};
+///
+/// let mut connections = 0;
+/// let mut iter = restart_with(|| {
+///     connections += 1;
+///     ["hello", "world"]
+/// });
+///
+/// assert_eq!(iter.next_infinite(), "hello");
+/// assert_eq!(iter.next_infinite(), "world");
+/// assert_eq!(iter.next_infinite(), "hello");
+/// drop(iter);
+/// assert_eq!(connections, 2);
+/// ```
+pub fn restart_with<F, U>(factory: F) -> RestartWith<F, U>
+where
+    F: FnMut() -> U,
+    U: IntoIterator,
+{
+    RestartWith {
+        factory,
+        iter: None,
+    }
+}
+
+/// An iterator that restarts itself from a closure whenever it ends.
+///
+/// This `struct` is created by [`restart_with`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RestartWith<F, U: IntoIterator> {
+    factory: F,
+    iter: Option<U::IntoIter>,
+}
+
+impl<F, U> Iterator for RestartWith<F, U>
+where
+    F: FnMut() -> U,
+    U: IntoIterator,
+{
+    type Item = U::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<F, U> InfiniteIterator for RestartWith<F, U>
+where
+    F: FnMut() -> U,
+    U: IntoIterator,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        loop {
+            if let Some(item) = self.iter.as_mut().and_then(Iterator::next) {
+                break item;
+            }
+            self.iter = Some((self.factory)().into_iter());
+        }
+    }
+}
@@ -0,0 +1,202 @@
+use crate::InfiniteIterator;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Create an infinite iterator that yields an item from each iterator
+/// of an array in turn.
+///
+/// Unlike `round_robin_vec`,
+/// this checks at compile time that the array is non-empty.
+///
+/// # Examples
+///
+/// Fairly multiplexing several sources:
+///
+/// ```
+/// use infinite_iterator::{round_robin, InfiniteIterator, InfiniteIteratorExt};
+///
+/// let mut iter = round_robin([(0..).step_by(3), (1..).step_by(3), (2..).step_by(3)]);
+/// assert_eq!(iter.next_array(), [0, 1, 2, 3, 4, 5, 6]);
+/// ```
+///
+/// Empty arrays are rejected at compile time:
+///
+/// ```compile_fail
+/// let iter = infinite_iterator::round_robin::<core::ops::RangeFrom<u32>, 0>([]);
+/// ```
+pub fn round_robin<I: InfiniteIterator, const N: usize>(iterators: [I; N]) -> RoundRobin<[I; N]> {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertNonEmpty::<N>::OK;
+    RoundRobin {
+        iters: iterators,
+        pos: 0,
+    }
+}
+
+struct AssertNonEmpty<const N: usize>;
+impl<const N: usize> AssertNonEmpty<N> {
+    const OK: () = assert!(N != 0, "cannot round-robin an empty array");
+}
+
+/// Create an infinite iterator that yields an item from each iterator
+/// of a `Vec` in turn.
+///
+/// Returns [`None`] if the `Vec` is empty,
+/// in which case there would be nothing to yield.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{round_robin_vec, InfiniteIterator, InfiniteIteratorExt};
+///
+/// let sources = (0..3).map(|id| core::iter::repeat(id)).collect();
+/// let mut iter = round_robin_vec(sources).unwrap();
+/// assert_eq!(iter.next_array(), [0, 1, 2, 0]);
+///
+/// assert!(round_robin_vec(Vec::<core::ops::RangeFrom<u8>>::new()).is_none());
+/// ```
+#[cfg(feature = "alloc")]
+pub fn round_robin_vec<I: InfiniteIterator>(iterators: Vec<I>) -> Option<RoundRobin<Vec<I>>> {
+    if iterators.is_empty() {
+        return None;
+    }
+    Some(RoundRobin {
+        iters: iterators,
+        pos: 0,
+    })
+}
+
+/// An infinite iterator that yields an item from each of
+/// a non-empty collection of iterators in turn.
+///
+/// This `struct` is created by [`round_robin`] and `round_robin_vec`.
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RoundRobin<C> {
+    /// Never empty.
+    iters: C,
+    /// Always in bounds of `iters`.
+    pos: usize,
+}
+
+impl<C> RoundRobin<C> {
+    /// Extract the underlying collection of iterators.
+    pub fn into_inner(self) -> C {
+        self.iters
+    }
+}
+
+macro_rules! impl_round_robin {
+    ($(#[$attr:meta])* impl<I $(, const $n:ident: usize)?> for $c:ty) => {
+        $(#[$attr])*
+        impl<I: InfiniteIterator $(, const $n: usize)?> Iterator for RoundRobin<$c> {
+            type Item = I::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                Some(self.next_infinite())
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (usize::MAX, None)
+            }
+        }
+
+        $(#[$attr])*
+        impl<I: InfiniteIterator $(, const $n: usize)?> InfiniteIterator for RoundRobin<$c> {
+            fn next_infinite(&mut self) -> Self::Item {
+                let item = self.iters[self.pos].next_infinite();
+                self.pos = (self.pos + 1) % self.iters.len();
+                item
+            }
+        }
+    };
+}
+
+impl_round_robin!(impl<I, const N: usize> for [I; N]);
+impl_round_robin!(#[cfg(feature = "alloc")] impl<I> for Vec<I>);
+
+/// Create an infinite iterator that yields items from each iterator of an array in turn,
+/// taking as many consecutive items from each iterator as its weight.
+///
+/// Iterators with a weight of zero are never advanced.
+///
+/// # Panics
+///
+/// Panics if every weight is zero.
+///
+/// # Examples
+///
+/// Generating a traffic mix of three reads to every write:
+///
+/// ```
+/// use infinite_iterator::{weighted_round_robin, InfiniteIterator, InfiniteIteratorExt};
+///
+/// let reads = core::iter::repeat("read");
+/// let writes = core::iter::repeat("write");
+/// let mut traffic = weighted_round_robin([reads, writes], [3, 1]);
+/// assert_eq!(
+///     traffic.next_array(),
+///     ["read", "read", "read", "write", "read", "read", "read", "write"],
+/// );
+/// ```
+pub fn weighted_round_robin<I: InfiniteIterator, const N: usize>(
+    iterators: [I; N],
+    weights: [usize; N],
+) -> WeightedRoundRobin<I, N> {
+    assert!(
+        weights.iter().any(|&weight| weight != 0),
+        "at least one weight must be non-zero"
+    );
+    WeightedRoundRobin {
+        iters: iterators,
+        weights,
+        pos: 0,
+        remaining: weights[0],
+    }
+}
+
+/// An infinite iterator that yields items from each of several iterators in turn,
+/// according to their weights.
+///
+/// This `struct` is created by [`weighted_round_robin`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WeightedRoundRobin<I, const N: usize> {
+    iters: [I; N],
+    /// At least one weight is non-zero.
+    weights: [usize; N],
+    /// Always in bounds of `iters`.
+    pos: usize,
+    /// The number of items left to take from `iters[pos]`.
+    remaining: usize,
+}
+
+impl<I, const N: usize> WeightedRoundRobin<I, N> {
+    /// Extract the underlying array of iterators.
+    pub fn into_inner(self) -> [I; N] {
+        self.iters
+    }
+}
+
+impl<I: InfiniteIterator, const N: usize> Iterator for WeightedRoundRobin<I, N> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<I: InfiniteIterator, const N: usize> InfiniteIterator for WeightedRoundRobin<I, N> {
+    fn next_infinite(&mut self) -> Self::Item {
+        while self.remaining == 0 {
+            self.pos = (self.pos + 1) % N;
+            self.remaining = self.weights[self.pos];
+        }
+        self.remaining -= 1;
+        self.iters[self.pos].next_infinite()
+    }
+}
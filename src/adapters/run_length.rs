@@ -0,0 +1,125 @@
+use crate::InfiniteIterator;
+
+/// An infinite iterator over the runs of equal consecutive items
+/// of another iterator, along with their lengths.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::run_length_encode`](crate::InfiniteIteratorExt::run_length_encode).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RunLengthEncode<I: Iterator> {
+    iter: I,
+    /// The first item of the next run,
+    /// which had to be pulled to find the end of the previous run.
+    pending: Option<I::Item>,
+}
+
+impl<I: Iterator> RunLengthEncode<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            pending: None,
+        }
+    }
+}
+
+impl<I> Iterator for RunLengthEncode<I>
+where
+    I: InfiniteIterator,
+    I::Item: PartialEq,
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<I> InfiniteIterator for RunLengthEncode<I>
+where
+    I: InfiniteIterator,
+    I::Item: PartialEq,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let current = match self.pending.take() {
+            Some(item) => item,
+            None => self.iter.next_infinite(),
+        };
+        let mut len = 1;
+        loop {
+            let item = self.iter.next_infinite();
+            if item != current {
+                self.pending = Some(item);
+                break (current, len);
+            }
+            len += 1;
+        }
+    }
+}
+
+/// An infinite iterator that expands pairs of an item and a count
+/// into that many copies of the item.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::run_length_decode`](crate::InfiniteIteratorExt::run_length_decode).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct RunLengthDecode<I, T> {
+    iter: I,
+    /// The item of the current run; always `Some` if `remaining` is non-zero.
+    current: Option<T>,
+    /// The number of copies of `current` left to yield.
+    remaining: usize,
+}
+
+impl<I, T> RunLengthDecode<I, T> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            current: None,
+            remaining: 0,
+        }
+    }
+}
+
+impl<I, T, N> Iterator for RunLengthDecode<I, T>
+where
+    I: InfiniteIterator<Item = (T, N)>,
+    T: Clone,
+    N: Into<usize>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, None)
+    }
+}
+
+impl<I, T, N> InfiniteIterator for RunLengthDecode<I, T>
+where
+    I: InfiniteIterator<Item = (T, N)>,
+    T: Clone,
+    N: Into<usize>,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        while self.remaining == 0 {
+            let (item, len) = self.iter.next_infinite();
+            self.current = Some(item);
+            self.remaining = len.into();
+        }
+        self.remaining -= 1;
+        if self.remaining == 0 {
+            self.current.take().unwrap()
+        } else {
+            self.current.clone().unwrap()
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use crate::InfiniteIterator;
+
+/// An infinite iterator that maps items through a closure with mutable state.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::scan_infinite`](crate::InfiniteIteratorExt::scan_infinite).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ScanInfinite<I, S, F> {
+    iter: I,
+    state: S,
+    f: F,
+}
+
+impl<I, S, F> ScanInfinite<I, S, F> {
+    pub(crate) fn new(iter: I, state: S, f: F) -> Self {
+        Self { iter, state, f }
+    }
+
+    /// Get a shared reference to the current state.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Get a unique reference to the current state.
+    pub fn state_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+
+    /// Extract the current state.
+    pub fn into_state(self) -> S {
+        self.state
+    }
+}
+
+impl<I, S, F, B> Iterator for ScanInfinite<I, S, F>
+where
+    I: InfiniteIterator,
+    F: FnMut(&mut S, I::Item) -> B,
+{
+    type Item = B;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, S, F, B> InfiniteIterator for ScanInfinite<I, S, F>
+where
+    I: InfiniteIterator,
+    F: FnMut(&mut S, I::Item) -> B,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        (self.f)(&mut self.state, self.iter.next_infinite())
+    }
+}
@@ -0,0 +1,154 @@
+use crate::InfiniteIterator;
+use crate::InfinitePeekable;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Formatter};
+
+macro_rules! set_op {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[must_use = "iterators are lazy and do nothing unless consumed"]
+        pub struct $name<A: Iterator, B: Iterator> {
+            a: InfinitePeekable<A>,
+            b: InfinitePeekable<B>,
+        }
+
+        impl<A: Iterator, B: Iterator> $name<A, B> {
+            pub(crate) fn new(a: A, b: B) -> Self {
+                Self {
+                    a: InfinitePeekable::new(a),
+                    b: InfinitePeekable::new(b),
+                }
+            }
+        }
+
+        impl<A, B> Clone for $name<A, B>
+        where
+            A: Iterator + Clone,
+            B: Iterator<Item = A::Item> + Clone,
+            A::Item: Clone,
+        {
+            fn clone(&self) -> Self {
+                Self {
+                    a: self.a.clone(),
+                    b: self.b.clone(),
+                }
+            }
+        }
+
+        impl<A, B> Debug for $name<A, B>
+        where
+            A: Iterator + Debug,
+            B: Iterator<Item = A::Item> + Debug,
+            A::Item: Debug,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("a", &self.a)
+                    .field("b", &self.b)
+                    .finish()
+            }
+        }
+
+        impl<A, B> Iterator for $name<A, B>
+        where
+            A: InfiniteIterator,
+            B: InfiniteIterator<Item = A::Item>,
+            A::Item: Ord,
+        {
+            type Item = A::Item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                Some(self.next_infinite())
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (0, None)
+            }
+        }
+    };
+}
+
+set_op! {
+    /// An infinite iterator over the union
+    /// of two strictly increasing infinite iterators.
+    ///
+    /// This `struct` is created by
+    /// [`InfiniteIteratorExt::union`](crate::InfiniteIteratorExt::union).
+    Union
+}
+
+impl<A, B> InfiniteIterator for Union<A, B>
+where
+    A: InfiniteIterator,
+    B: InfiniteIterator<Item = A::Item>,
+    A::Item: Ord,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        match self.a.peek().cmp(self.b.peek()) {
+            Ordering::Less => self.a.next_infinite(),
+            Ordering::Greater => self.b.next_infinite(),
+            Ordering::Equal => {
+                self.b.next_infinite();
+                self.a.next_infinite()
+            }
+        }
+    }
+}
+
+set_op! {
+    /// An infinite iterator over the intersection
+    /// of two strictly increasing infinite iterators.
+    ///
+    /// This `struct` is created by
+    /// [`InfiniteIteratorExt::intersection`](crate::InfiniteIteratorExt::intersection).
+    Intersection
+}
+
+impl<A, B> InfiniteIterator for Intersection<A, B>
+where
+    A: InfiniteIterator,
+    B: InfiniteIterator<Item = A::Item>,
+    A::Item: Ord,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        loop {
+            match self.a.peek().cmp(self.b.peek()) {
+                Ordering::Less => drop(self.a.next_infinite()),
+                Ordering::Greater => drop(self.b.next_infinite()),
+                Ordering::Equal => {
+                    self.b.next_infinite();
+                    break self.a.next_infinite();
+                }
+            }
+        }
+    }
+}
+
+set_op! {
+    /// An infinite iterator over the items of one strictly increasing infinite iterator
+    /// that are not present in another.
+    ///
+    /// This `struct` is created by
+    /// [`InfiniteIteratorExt::difference`](crate::InfiniteIteratorExt::difference).
+    Difference
+}
+
+impl<A, B> InfiniteIterator for Difference<A, B>
+where
+    A: InfiniteIterator,
+    B: InfiniteIterator<Item = A::Item>,
+    A::Item: Ord,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        loop {
+            match self.a.peek().cmp(self.b.peek()) {
+                Ordering::Less => break self.a.next_infinite(),
+                Ordering::Greater => drop(self.b.next_infinite()),
+                Ordering::Equal => {
+                    self.a.next_infinite();
+                    self.b.next_infinite();
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::InfiniteIterator;
+use core::mem;
+
+/// Create an infinite iterator where each successive item
+/// is computed based on the preceding one.
+///
+/// This is like [`iter::successors`](core::iter::successors),
+/// except the closure returns `T` rather than `Option<T>`,
+/// so the iterator never ends.
+/// The iterator yields `first`, `succ(&first)`, `succ(&succ(&first))`, and so on.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{successors_infinite, InfiniteIterator};
+///
+/// let mut powers_of_ten = successors_infinite(1_u32, |n| n.wrapping_mul(10));
+/// assert_eq!(powers_of_ten.next_infinite(), 1);
+/// assert_eq!(powers_of_ten.next_infinite(), 10);
+/// assert_eq!(powers_of_ten.next_infinite(), 100);
+/// ```
+pub fn successors_infinite<T, F>(first: T, succ: F) -> SuccessorsInfinite<T, F>
+where
+    F: FnMut(&T) -> T,
+{
+    SuccessorsInfinite { next: first, succ }
+}
+
+/// Create an infinite iterator that repeatedly applies a function to a seed value.
+///
+/// The iterator yields `seed`, `f(&seed)`, `f(&f(&seed))`, and so on.
+/// This is the same as [`successors_infinite`],
+/// provided under the name used by the `itertools` crate.
+///
+/// # Examples
+///
+/// Iterating towards a fixed point:
+///
+/// ```
+/// use infinite_iterator::{iterate, InfiniteIterator};
+///
+/// let mut collatz = iterate(6_u32, |&n| if n % 2 == 0 { n / 2 } else { 3 * n + 1 });
+/// assert_eq!(collatz.find_infinite(|&n| n == 1), 1);
+///
+/// let mut sqrt_2 = iterate(1.0_f64, |x| (x + 2.0 / x) / 2.0);
+/// let approx = sqrt_2.find_infinite(|x| (x * x - 2.0).abs() < 1e-12);
+/// assert!((approx - core::f64::consts::SQRT_2).abs() < 1e-12);
+/// ```
+pub fn iterate<T, F>(seed: T, f: F) -> SuccessorsInfinite<T, F>
+where
+    F: FnMut(&T) -> T,
+{
+    successors_infinite(seed, f)
+}
+
+/// An infinite iterator where each successive item
+/// is computed based on the preceding one.
+///
+/// This `struct` is created by [`successors_infinite`] and [`iterate`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SuccessorsInfinite<T, F> {
+    next: T,
+    succ: F,
+}
+
+impl<T, F> Iterator for SuccessorsInfinite<T, F>
+where
+    F: FnMut(&T) -> T,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T, F> InfiniteIterator for SuccessorsInfinite<T, F>
+where
+    F: FnMut(&T) -> T,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let next = (self.succ)(&self.next);
+        mem::replace(&mut self.next, next)
+    }
+}
@@ -0,0 +1,104 @@
+use crate::InfiniteIterator;
+use crate::IntoInfiniteIterator;
+
+/// Create an infinite iterator that,
+/// for each item of `selector`,
+/// yields the next item of the source it selects.
+///
+/// Items of `selector` are converted to indices into `sources` with [`Into<usize>`],
+/// so they can be integers or a user-defined `enum`.
+/// Sources that are not selected are not advanced.
+///
+/// # Panics
+///
+/// The iterator panics if `selector` yields an index
+/// that is out of bounds of `sources`.
+///
+/// # Examples
+///
+/// Consuming from sources chosen by a state machine:
+///
+/// ```
+/// use infinite_iterator::{cycle_array, switch, InfiniteIterator, InfiniteIteratorExt};
+///
+/// #[derive(Clone, Copy)]
+/// enum Channel {
+///     Audio,
+///     Video,
+/// }
+///
+/// impl From<Channel> for usize {
+///     fn from(channel: Channel) -> Self {
+///         channel as usize
+///     }
+/// }
+///
+/// let schedule = cycle_array(&[Channel::Video, Channel::Audio, Channel::Audio]).copied();
+/// let audio_frames = 0..;
+/// let video_frames = 100..;
+/// let mut stream = switch(schedule, [audio_frames, video_frames]);
+/// assert_eq!(stream.next_array(), [100, 0, 1, 101, 2, 3]);
+/// ```
+pub fn switch<S, I, const N: usize>(
+    selector: S,
+    sources: [I; N],
+) -> Switch<S::IntoInfiniteIter, I, N>
+where
+    S: IntoInfiniteIterator,
+    S::Item: Into<usize>,
+    I: InfiniteIterator,
+{
+    Switch {
+        selector: selector.into_infinite_iter(),
+        sources,
+    }
+}
+
+/// An infinite iterator that yields items from sources chosen by a selector iterator.
+///
+/// This `struct` is created by [`switch`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Switch<S, I, const N: usize> {
+    selector: S,
+    sources: [I; N],
+}
+
+impl<S, I, const N: usize> Switch<S, I, N> {
+    /// Extract the selector iterator and the sources.
+    pub fn into_parts(self) -> (S, [I; N]) {
+        (self.selector, self.sources)
+    }
+}
+
+impl<S, I, const N: usize> Iterator for Switch<S, I, N>
+where
+    S: InfiniteIterator,
+    S::Item: Into<usize>,
+    I: InfiniteIterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<S, I, const N: usize> InfiniteIterator for Switch<S, I, N>
+where
+    S: InfiniteIterator,
+    S::Item: Into<usize>,
+    I: InfiniteIterator,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let index = self.selector.next_infinite().into();
+        match self.sources.get_mut(index) {
+            Some(source) => source.next_infinite(),
+            None => panic!("selector index {index} out of range for {N} sources"),
+        }
+    }
+}
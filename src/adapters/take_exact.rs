@@ -0,0 +1,54 @@
+use crate::InfiniteIterator;
+use core::iter::FusedIterator;
+
+/// An iterator that yields exactly the first `n` items of an infinite iterator.
+///
+/// Unlike [`iter::Take`](core::iter::Take),
+/// this implements [`ExactSizeIterator`]
+/// (and `TrustedLen` with the `nightly` feature),
+/// since an infinite iterator always has at least `n` items.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::take_exact`](crate::InfiniteIteratorExt::take_exact).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TakeExact<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I> TakeExact<I> {
+    pub(crate) fn new(iter: I, n: usize) -> Self {
+        Self { iter, n }
+    }
+
+    /// Extract the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: InfiniteIterator> Iterator for TakeExact<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.n == 0 {
+            return None;
+        }
+        self.n -= 1;
+        Some(self.iter.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.n, Some(self.n))
+    }
+}
+
+impl<I: InfiniteIterator> ExactSizeIterator for TakeExact<I> {}
+
+impl<I: InfiniteIterator> FusedIterator for TakeExact<I> {}
+
+// SAFETY: `next_infinite` cannot return early,
+// so exactly `n` items are always yielded.
+#[cfg(feature = "nightly")]
+unsafe impl<I: InfiniteIterator> core::iter::TrustedLen for TakeExact<I> {}
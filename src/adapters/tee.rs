@@ -0,0 +1,141 @@
+use crate::InfiniteIterator;
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt::{self, Debug, Formatter};
+
+/// The state shared between all the handles of a tee.
+#[derive(Debug)]
+pub(crate) struct Shared<I: Iterator> {
+    iter: I,
+    /// Items that have been pulled from `iter`
+    /// but not yet yielded by every handle.
+    buf: VecDeque<I::Item>,
+    /// For each handle, the index in `buf` of the next item it will yield,
+    /// or `None` if the handle has been dropped.
+    offsets: Vec<Option<usize>>,
+}
+
+impl<I: Iterator> Shared<I> {
+    pub(crate) fn remove(&mut self, handle: usize) {
+        self.offsets[handle] = None;
+        self.trim();
+    }
+
+    /// Remove the items at the front of the buffer
+    /// that have been yielded by every live handle.
+    fn trim(&mut self) {
+        let done = self.offsets.iter().flatten().copied().min();
+        let done = done.unwrap_or(self.buf.len());
+        if done == 0 {
+            return;
+        }
+        self.buf.drain(..done);
+        for offset in self.offsets.iter_mut().flatten() {
+            *offset -= done;
+        }
+    }
+}
+
+impl<I: InfiniteIterator> Shared<I>
+where
+    I::Item: Clone,
+{
+    pub(crate) fn new(iter: I, handles: usize) -> Self {
+        Self {
+            iter,
+            buf: VecDeque::new(),
+            offsets: alloc::vec![Some(0); handles],
+        }
+    }
+
+    pub(crate) fn next(&mut self, handle: usize) -> I::Item {
+        let offset = self.offsets[handle].as_mut().unwrap();
+        let item = match self.buf.get(*offset) {
+            Some(item) => item.clone(),
+            None => {
+                let item = self.iter.next_infinite();
+                self.buf.push_back(item.clone());
+                item
+            }
+        };
+        *offset += 1;
+        if *offset == 1 {
+            self.trim();
+        }
+        item
+    }
+
+    pub(crate) fn size_hint(&self, handle: usize) -> (usize, Option<usize>) {
+        let buffered = self.buf.len() - self.offsets[handle].unwrap();
+        (self.iter.size_hint().0.saturating_add(buffered), None)
+    }
+}
+
+/// One of several handles to a shared infinite iterator,
+/// each of which yields every item.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::tee`](crate::InfiniteIteratorExt::tee) and
+/// [`InfiniteIteratorExt::tee_n`](crate::InfiniteIteratorExt::tee_n).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Tee<I: Iterator> {
+    shared: Rc<RefCell<Shared<I>>>,
+    handle: usize,
+}
+
+impl<I: InfiniteIterator> Tee<I>
+where
+    I::Item: Clone,
+{
+    pub(crate) fn new<const N: usize>(iter: I) -> [Self; N] {
+        let shared = Rc::new(RefCell::new(Shared::new(iter, N)));
+        core::array::from_fn(|handle| Self {
+            shared: shared.clone(),
+            handle,
+        })
+    }
+}
+
+impl<I: InfiniteIterator> Iterator for Tee<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.shared.borrow().size_hint(self.handle)
+    }
+}
+
+impl<I: InfiniteIterator> InfiniteIterator for Tee<I>
+where
+    I::Item: Clone,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        self.shared.borrow_mut().next(self.handle)
+    }
+}
+
+impl<I: Iterator + Debug> Debug for Tee<I>
+where
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tee")
+            .field("shared", &self.shared)
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Drop for Tee<I> {
+    fn drop(&mut self) {
+        self.shared.borrow_mut().remove(self.handle);
+    }
+}
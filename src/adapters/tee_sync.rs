@@ -0,0 +1,83 @@
+use super::tee::Shared;
+use crate::InfiniteIterator;
+use core::fmt::{self, Debug, Formatter};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// One of several thread-safe handles to a shared infinite iterator,
+/// each of which yields every item.
+///
+/// This is like [`Tee`](crate::Tee),
+/// except the handles are [`Send`] and [`Sync`]
+/// (as long as the iterator and its items are [`Send`]),
+/// so they can be consumed from different threads.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::tee_sync`](crate::InfiniteIteratorExt::tee_sync) and
+/// [`InfiniteIteratorExt::tee_sync_n`](crate::InfiniteIteratorExt::tee_sync_n).
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TeeSync<I: Iterator> {
+    shared: Arc<Mutex<Shared<I>>>,
+    handle: usize,
+}
+
+impl<I: Iterator> TeeSync<I> {
+    fn lock(&self) -> MutexGuard<'_, Shared<I>> {
+        // The shared state is kept consistent even if a panic occurs while it is locked.
+        self.shared.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<I: InfiniteIterator> TeeSync<I>
+where
+    I::Item: Clone,
+{
+    pub(crate) fn new<const N: usize>(iter: I) -> [Self; N] {
+        let shared = Arc::new(Mutex::new(Shared::new(iter, N)));
+        core::array::from_fn(|handle| Self {
+            shared: shared.clone(),
+            handle,
+        })
+    }
+}
+
+impl<I: InfiniteIterator> Iterator for TeeSync<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lock().size_hint(self.handle)
+    }
+}
+
+impl<I: InfiniteIterator> InfiniteIterator for TeeSync<I>
+where
+    I::Item: Clone,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        self.lock().next(self.handle)
+    }
+}
+
+impl<I: Iterator + Debug> Debug for TeeSync<I>
+where
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TeeSync")
+            .field("shared", &self.shared)
+            .field("handle", &self.handle)
+            .finish()
+    }
+}
+
+impl<I: Iterator> Drop for TeeSync<I> {
+    fn drop(&mut self) {
+        self.lock().remove(self.handle);
+    }
+}
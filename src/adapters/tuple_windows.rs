@@ -0,0 +1,56 @@
+use crate::HomogeneousTuple;
+use crate::InfiniteIterator;
+
+/// An infinite iterator over overlapping tuples of another iterator's items.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::tuple_windows`](crate::InfiniteIteratorExt::tuple_windows)
+/// and [`InfiniteIteratorExt::pairwise`](crate::InfiniteIteratorExt::pairwise).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TupleWindows<I, T> {
+    iter: I,
+    /// The most recently yielded window, or `None` before the first window.
+    last: Option<T>,
+}
+
+impl<I, T> TupleWindows<I, T> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter, last: None }
+    }
+}
+
+impl<I, T> Iterator for TupleWindows<I, T>
+where
+    I: InfiniteIterator,
+    T: HomogeneousTuple<Item = I::Item> + Clone,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = self.iter.size_hint().0;
+        match self.last {
+            Some(_) => (lower, None),
+            None => (lower.saturating_sub(T::ARITY - 1), None),
+        }
+    }
+}
+
+impl<I, T> InfiniteIterator for TupleWindows<I, T>
+where
+    I: InfiniteIterator,
+    T: HomogeneousTuple<Item = I::Item> + Clone,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let window = match self.last.take() {
+            Some(last) => last.shift(self.iter.next_infinite()),
+            None => T::from_infinite_iter(&mut self.iter),
+        };
+        self.last = Some(window.clone());
+        window
+    }
+}
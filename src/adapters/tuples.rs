@@ -0,0 +1,54 @@
+use crate::HomogeneousTuple;
+use crate::InfiniteIterator;
+use core::marker::PhantomData;
+
+/// An infinite iterator over non-overlapping tuples of another iterator's items.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::tuples`](crate::InfiniteIteratorExt::tuples).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Tuples<I, T> {
+    iter: I,
+    tuple: PhantomData<fn() -> T>,
+}
+
+impl<I, T> Tuples<I, T> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            tuple: PhantomData,
+        }
+    }
+
+    /// Extract the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I, T> Iterator for Tuples<I, T>
+where
+    I: InfiniteIterator,
+    T: HomogeneousTuple<Item = I::Item>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.size_hint().0 / T::ARITY, None)
+    }
+}
+
+impl<I, T> InfiniteIterator for Tuples<I, T>
+where
+    I: InfiniteIterator,
+    T: HomogeneousTuple<Item = I::Item>,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        T::from_infinite_iter(&mut self.iter)
+    }
+}
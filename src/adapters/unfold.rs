@@ -0,0 +1,87 @@
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator from a state and a closure
+/// that produces each item from a mutable reference to that state.
+///
+/// This is like `itertools::unfold`,
+/// except the closure returns the item directly rather than an `Option`,
+/// so the iterator never ends.
+///
+/// # Examples
+///
+/// Generating the Fibonacci sequence:
+///
+/// ```
+/// use infinite_iterator::{unfold_infinite, InfiniteIterator};
+///
+/// let mut fib = unfold_infinite((0_u32, 1_u32), |(a, b)| {
+///     let item = *a;
+///     *a = *b;
+///     *b = item.wrapping_add(*b);
+///     item
+/// });
+///
+/// assert_eq!(fib.next_infinite(), 0);
+/// assert_eq!(fib.next_infinite(), 1);
+/// assert_eq!(fib.next_infinite(), 1);
+/// assert_eq!(fib.next_infinite(), 2);
+/// assert_eq!(fib.next_infinite(), 3);
+/// assert_eq!(*fib.state(), (5, 8));
+/// ```
+pub fn unfold_infinite<S, F, T>(state: S, f: F) -> UnfoldInfinite<S, F>
+where
+    F: FnMut(&mut S) -> T,
+{
+    UnfoldInfinite { state, f }
+}
+
+/// An infinite iterator driven by a state and a closure.
+///
+/// This `struct` is created by [`unfold_infinite`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct UnfoldInfinite<S, F> {
+    state: S,
+    f: F,
+}
+
+impl<S, F> UnfoldInfinite<S, F> {
+    /// Get a shared reference to the current state.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Get a unique reference to the current state.
+    pub fn state_mut(&mut self) -> &mut S {
+        &mut self.state
+    }
+
+    /// Extract the current state.
+    pub fn into_state(self) -> S {
+        self.state
+    }
+}
+
+impl<S, F, T> Iterator for UnfoldInfinite<S, F>
+where
+    F: FnMut(&mut S) -> T,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<S, F, T> InfiniteIterator for UnfoldInfinite<S, F>
+where
+    F: FnMut(&mut S) -> T,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        (self.f)(&mut self.state)
+    }
+}
@@ -0,0 +1,61 @@
+use crate::InfiniteIterator;
+use crate::InfiniteIteratorExt;
+
+/// An infinite iterator over overlapping windows of another iterator's items.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::windows`](crate::InfiniteIteratorExt::windows).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Windows<I: Iterator, const N: usize> {
+    iter: I,
+    /// The most recently yielded window, or `None` before the first window.
+    window: Option<[I::Item; N]>,
+}
+
+impl<I: Iterator, const N: usize> Windows<I, N> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter, window: None }
+    }
+}
+
+impl<I, const N: usize> Iterator for Windows<I, N>
+where
+    I: InfiniteIterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = self.iter.size_hint().0;
+        match (&self.window, N) {
+            (_, 0) => (usize::MAX, None),
+            (None, _) => (lower.saturating_sub(N - 1), None),
+            (Some(_), _) => (lower, None),
+        }
+    }
+}
+
+impl<I, const N: usize> InfiniteIterator for Windows<I, N>
+where
+    I: InfiniteIterator,
+    I::Item: Clone,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        match &mut self.window {
+            Some(window) => {
+                if N != 0 {
+                    // Move the oldest item to the end, then overwrite it.
+                    window.rotate_left(1);
+                    window[N - 1] = self.iter.next_infinite();
+                }
+                window.clone()
+            }
+            None => self.window.insert(self.iter.next_array()).clone(),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::InfiniteIterator;
+
+/// An infinite iterator that combines the items of two infinite iterators
+/// with a closure.
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::zip_with`](crate::InfiniteIteratorExt::zip_with).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ZipWith<A, B, F> {
+    a: A,
+    b: B,
+    f: F,
+}
+
+impl<A, B, F> ZipWith<A, B, F> {
+    pub(crate) fn new(a: A, b: B, f: F) -> Self {
+        Self { a, b, f }
+    }
+}
+
+impl<A, B, F, T> Iterator for ZipWith<A, B, F>
+where
+    A: InfiniteIterator,
+    B: InfiniteIterator,
+    F: FnMut(A::Item, B::Item) -> T,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let lower = Ord::min(self.a.size_hint().0, self.b.size_hint().0);
+        (lower, None)
+    }
+}
+
+impl<A, B, F, T> InfiniteIterator for ZipWith<A, B, F>
+where
+    A: InfiniteIterator,
+    B: InfiniteIterator,
+    F: FnMut(A::Item, B::Item) -> T,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let a = self.a.next_infinite();
+        (self.f)(a, self.b.next_infinite())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::InfiniteIterator;
+
+/// Treat an iterator as infinite,
+/// panicking if it ever ends.
+///
+/// This is useful for iterators which are known to be infinite
+/// but for which [`InfiniteIterator`] is not (or cannot be) implemented,
+/// for example receiving from a channel whose sender is never dropped.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{assert_infinite, InfiniteIterator};
+/// use std::sync::mpsc;
+///
+/// let (sender, receiver) = mpsc::channel();
+/// std::thread::spawn(move || {
+///     for num in 0.. {
+///         if sender.send(num).is_err() {
+///             break;
+///         }
+///     }
+/// });
+///
+/// let mut iter = assert_infinite(receiver);
+/// assert_eq!(iter.next_infinite(), 0);
+/// assert_eq!(iter.next_infinite(), 1);
+/// ```
+///
+/// If the iterator does end, the program panics:
+///
+/// ```should_panic
+/// use infinite_iterator::{assert_infinite, InfiniteIterator};
+///
+/// let mut iter = assert_infinite([1, 2]);
+/// iter.next_infinite();
+/// iter.next_infinite();
+/// iter.next_infinite();
+/// ```
+pub fn assert_infinite<I: IntoIterator>(iter: I) -> AssertInfinite<I::IntoIter> {
+    AssertInfinite {
+        iter: iter.into_iter(),
+    }
+}
+
+/// An iterator that is asserted to be infinite.
+///
+/// This `struct` is created by [`assert_infinite`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AssertInfinite<I> {
+    iter: I,
+}
+
+impl<I> AssertInfinite<I> {
+    /// Extract the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Iterator for AssertInfinite<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.size_hint().0, None)
+    }
+}
+
+impl<I: Iterator> InfiniteIterator for AssertInfinite<I> {
+    fn next_infinite(&mut self) -> Self::Item {
+        match self.iter.next() {
+            Some(item) => item,
+            None => panic!("iterator passed to `assert_infinite` ended"),
+        }
+    }
+}
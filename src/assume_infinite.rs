@@ -0,0 +1,82 @@
+use crate::InfiniteIterator;
+use crate::TrustedInfinite;
+use crate::TrustedToken;
+
+/// Treat an iterator as infinite without checking.
+///
+/// This is the unchecked counterpart of [`assert_infinite`](crate::assert_infinite):
+/// instead of panicking when the iterator ends,
+/// the ending of the iterator is assumed to be impossible.
+/// The resulting iterator implements [`TrustedInfinite`].
+///
+/// # Safety
+///
+/// The iterator must never end:
+/// its [`Iterator::next`] method must never return [`None`].
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{assume_infinite, InfiniteIterator};
+///
+/// let mut state = 1_u32;
+/// let lcg = std::iter::from_fn(|| {
+///     state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+///     Some(state)
+/// });
+///
+/// // SAFETY: The closure always returns `Some`.
+/// let mut iter = unsafe { assume_infinite(lcg) };
+/// assert_eq!(iter.next_infinite(), 1_015_568_748);
+/// ```
+pub unsafe fn assume_infinite<I: IntoIterator>(iter: I) -> AssumeInfinite<I::IntoIter> {
+    AssumeInfinite {
+        iter: iter.into_iter(),
+    }
+}
+
+/// An iterator that is assumed to be infinite.
+///
+/// This `struct` is created by [`assume_infinite`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct AssumeInfinite<I> {
+    iter: I,
+}
+
+impl<I> AssumeInfinite<I> {
+    /// Extract the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: Iterator> Iterator for AssumeInfinite<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.iter.size_hint().0, None)
+    }
+}
+
+impl<I: Iterator> InfiniteIterator for AssumeInfinite<I> {
+    fn next_infinite(&mut self) -> Self::Item {
+        match self.iter.next() {
+            Some(item) => item,
+            // SAFETY: The caller of `assume_infinite` guaranteed the iterator never ends.
+            None => unsafe { core::hint::unreachable_unchecked() },
+        }
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        Some(TrustedToken::new())
+    }
+}
+
+// SAFETY: `next` never returns `None`,
+// and no other `Iterator` methods are overridden.
+unsafe impl<I: Iterator> TrustedInfinite for AssumeInfinite<I> {}
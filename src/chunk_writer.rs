@@ -0,0 +1,94 @@
+use core::mem::MaybeUninit;
+
+/// A buffer of uninitialized slots
+/// that an [`InfiniteIterator`](crate::InfiniteIterator) fills with items in order.
+///
+/// This is passed to [`InfiniteIterator::next_chunk_into`](crate::InfiniteIterator::next_chunk_into)
+/// so that implementations can produce a whole chunk of items in a single call,
+/// even through a trait object.
+/// It can only be written to, one item at a time, from the front.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{ChunkWriter, InfiniteIterator, InfiniteIteratorExt};
+///
+/// struct Countdown(u32);
+///
+/// impl Iterator for Countdown {
+///     type Item = u32;
+///     fn next(&mut self) -> Option<u32> {
+///         Some(self.next_infinite())
+///     }
+/// }
+///
+/// impl InfiniteIterator for Countdown {
+///     fn next_infinite(&mut self) -> u32 {
+///         self.0 = self.0.wrapping_sub(1);
+///         self.0
+///     }
+///
+///     fn next_chunk_into(&mut self, chunk: &mut ChunkWriter<'_, u32>) {
+///         assert!(chunk.remaining() <= 3);
+///         while !chunk.is_full() {
+///             chunk.push(self.next_infinite());
+///         }
+///     }
+/// }
+///
+/// let iter: &mut dyn InfiniteIterator<Item = u32> = &mut Countdown(10);
+/// assert_eq!(iter.next_array(), [9, 8, 7]);
+/// ```
+#[derive(Debug)]
+pub struct ChunkWriter<'a, T> {
+    slots: &'a mut [MaybeUninit<T>],
+    /// The number of slots at the front that have been initialized.
+    filled: usize,
+}
+
+impl<'a, T> ChunkWriter<'a, T> {
+    pub(crate) fn new(slots: &'a mut [MaybeUninit<T>]) -> Self {
+        Self { slots, filled: 0 }
+    }
+
+    /// Write the next item into the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is already full.
+    pub fn push(&mut self, item: T) {
+        let slot = self
+            .slots
+            .get_mut(self.filled)
+            .expect("pushed to a full `ChunkWriter`");
+        slot.write(item);
+        self.filled += 1;
+    }
+
+    /// Get the number of items that still need to be written.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.slots.len() - self.filled
+    }
+
+    /// Check whether every slot has been written to.
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Get the filled slots as an initialized slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not full.
+    pub(crate) fn into_filled(self) -> &'a mut [T] {
+        assert!(
+            self.is_full(),
+            "`next_chunk_into` did not fill the whole chunk"
+        );
+        // SAFETY: Every slot has been initialized by `push`,
+        // and `MaybeUninit<T>` is guaranteed to have the same layout as `T`.
+        unsafe { &mut *(self.slots as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+}
@@ -0,0 +1,2075 @@
+use crate::overflow::{Integer, Policy};
+use crate::ArrayChunksInfinite;
+use crate::ChainInfinite;
+use crate::ChunkWriter;
+use crate::Dedup;
+use crate::DedupBy;
+use crate::DeltaDecode;
+use crate::DeltaEncode;
+use crate::Difference;
+use crate::HomogeneousTuple;
+use crate::InfiniteIterator;
+use crate::InfinitePeekable;
+use crate::InspectEvery;
+use crate::Interleave;
+use crate::Intersection;
+use crate::IntoInfiniteIterator;
+use crate::Lending;
+use crate::Lookahead;
+use crate::Merge;
+use crate::MergeBy;
+use crate::OrRepeat;
+use crate::OrRepeatWith;
+use crate::RunLengthDecode;
+use crate::RunLengthEncode;
+use crate::ScanInfinite;
+use crate::TakeExact;
+use crate::TupleWindows;
+use crate::Tuples;
+use crate::Union;
+use crate::Windows;
+use crate::ZipWith;
+use core::iter;
+use core::mem::MaybeUninit;
+use core::ops::ControlFlow;
+
+/// An extension trait providing combinators on [`InfiniteIterator`]s
+/// whose results are guaranteed to also be [`InfiniteIterator`]s.
+///
+/// Each method is equivalent to its counterpart on [`Iterator`],
+/// but will only compile if the resulting iterator never ends,
+/// so there is no need to check which adapters preserve infiniteness.
+///
+/// This trait is implemented for every [`InfiniteIterator`].
+pub trait InfiniteIteratorExt: InfiniteIterator {
+    /// Like [`Iterator::map`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (1..).map_infinite(|num| num * 2);
+    /// assert_eq!(iter.next_infinite(), 2);
+    /// assert_eq!(iter.next_infinite(), 4);
+    /// ```
+    fn map_infinite<B, F>(self, f: F) -> iter::Map<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> B,
+    {
+        self.map(f)
+    }
+
+    /// Like [`Iterator::filter`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// The predicate must return `true` infinitely often,
+    /// otherwise the resulting iterator will loop forever
+    /// looking for the next item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (1..).filter_infinite(|num| num % 3 == 0);
+    /// assert_eq!(iter.next_infinite(), 3);
+    /// assert_eq!(iter.next_infinite(), 6);
+    /// ```
+    fn filter_infinite<P>(self, predicate: P) -> iter::Filter<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.filter(predicate)
+    }
+
+    /// Like [`Iterator::filter_map`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// The closure must return `Some` infinitely often,
+    /// otherwise the resulting iterator will loop forever
+    /// looking for the next item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (1_u32..).filter_map_infinite(|num| num.checked_sub(5));
+    /// assert_eq!(iter.next_infinite(), 0);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// ```
+    fn filter_map_infinite<B, F>(self, f: F) -> iter::FilterMap<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<B>,
+    {
+        self.filter_map(f)
+    }
+
+    /// Like [`Iterator::zip`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (1..).zip_infinite(core::iter::repeat('a'));
+    /// assert_eq!(iter.next_infinite(), (1, 'a'));
+    /// assert_eq!(iter.next_infinite(), (2, 'a'));
+    /// ```
+    fn zip_infinite<U>(self, other: U) -> iter::Zip<Self, U>
+    where
+        Self: Sized,
+        U: InfiniteIterator,
+    {
+        self.zip(other)
+    }
+
+    /// Combine the items of this iterator and another infinite iterator
+    /// pairwise with a closure.
+    ///
+    /// This is equivalent to `self.zip_infinite(other).map_infinite(|(a, b)| f(a, b))`,
+    /// but with a shorter type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut products = (1..).zip_with((10..).step_by(10), |a, b| a * b);
+    /// assert_eq!(products.next_infinite(), 10);
+    /// assert_eq!(products.next_infinite(), 40);
+    /// assert_eq!(products.next_infinite(), 90);
+    /// ```
+    fn zip_with<U, F, T>(self, other: U, f: F) -> ZipWith<Self, U::IntoInfiniteIter, F>
+    where
+        Self: Sized,
+        U: IntoInfiniteIterator,
+        F: FnMut(Self::Item, U::Item) -> T,
+    {
+        ZipWith::new(self, other.into_infinite_iter(), f)
+    }
+
+    /// Alternate between the items of this iterator and another iterator,
+    /// starting with this one.
+    ///
+    /// The other iterator may be finite:
+    /// once it ends, the resulting iterator
+    /// continues with only the items of this iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).step_by(2).interleave((1..).step_by(2));
+    /// assert_eq!(iter.next_array(), [0, 1, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// With a finite operand:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = core::iter::repeat(0).interleave([1, 2]);
+    /// assert_eq!(iter.next_array(), [0, 1, 0, 2, 0, 0, 0]);
+    /// ```
+    fn interleave<U>(self, other: U) -> Interleave<Self, U::IntoIter>
+    where
+        Self: Sized,
+        U: IntoIterator<Item = Self::Item>,
+    {
+        Interleave::new(self, other.into_iter())
+    }
+
+    /// Merge this iterator and another infinite iterator,
+    /// both in ascending order,
+    /// into a single infinite iterator in ascending order.
+    ///
+    /// When two items are equal,
+    /// the one from this iterator is yielded first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).step_by(2).merge((0..).step_by(3));
+    /// assert_eq!(iter.next_array(), [0, 0, 2, 3, 4, 6, 6, 8]);
+    /// ```
+    fn merge<U>(self, other: U) -> Merge<Self, U::IntoInfiniteIter>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+        U: IntoInfiniteIterator<Item = Self::Item>,
+    {
+        MergeBy::new(self, other.into_infinite_iter(), PartialOrd::le)
+    }
+
+    /// Merge this iterator and another infinite iterator
+    /// into a single infinite iterator,
+    /// using `is_first` to decide which of the two next items comes first.
+    ///
+    /// `is_first` is passed the next item of this iterator
+    /// and the next item of the other iterator,
+    /// and returns `true` if the item of this iterator should be yielded first.
+    /// If both iterators are ordered consistently with `is_first`,
+    /// so is the result.
+    ///
+    /// # Examples
+    ///
+    /// Merging two event streams into one timeline:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// struct Event {
+    ///     timestamp: u64,
+    ///     source: &'static str,
+    /// }
+    ///
+    /// let ticks = (0..).step_by(10).map_infinite(|timestamp| Event { timestamp, source: "tick" });
+    /// let pings = (5..).step_by(15).map_infinite(|timestamp| Event { timestamp, source: "ping" });
+    ///
+    /// let mut timeline = ticks
+    ///     .merge_by(pings, |a, b| a.timestamp <= b.timestamp)
+    ///     .map_infinite(|event| (event.timestamp, event.source));
+    /// assert_eq!(
+    ///     timeline.next_array(),
+    ///     [(0, "tick"), (5, "ping"), (10, "tick"), (20, "tick"), (20, "ping")],
+    /// );
+    /// ```
+    fn merge_by<U, F>(self, other: U, is_first: F) -> MergeBy<Self, U::IntoInfiniteIter, F>
+    where
+        Self: Sized,
+        U: IntoInfiniteIterator<Item = Self::Item>,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        MergeBy::new(self, other.into_infinite_iter(), is_first)
+    }
+
+    /// Yield the union of this iterator and another infinite iterator,
+    /// both strictly increasing, in increasing order.
+    ///
+    /// Items present in both iterators are yielded once.
+    /// If either iterator is not strictly increasing,
+    /// the result is unspecified but still infinite.
+    ///
+    /// # Examples
+    ///
+    /// Merging two streams of IDs:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut ids = (0..).step_by(2).union((0..).step_by(3));
+    /// assert_eq!(ids.next_array(), [0, 2, 3, 4, 6, 8, 9, 10, 12]);
+    /// ```
+    fn union<U>(self, other: U) -> Union<Self, U::IntoInfiniteIter>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+        U: IntoInfiniteIterator<Item = Self::Item>,
+    {
+        Union::new(self, other.into_infinite_iter())
+    }
+
+    /// Yield the items present in both this iterator and another infinite iterator,
+    /// both strictly increasing, in increasing order.
+    ///
+    /// The intersection itself must be infinite,
+    /// otherwise the resulting iterator will loop forever
+    /// looking for the next common item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut common = (0..).step_by(4).intersection((0..).step_by(6));
+    /// assert_eq!(common.next_array(), [0, 12, 24, 36]);
+    /// ```
+    fn intersection<U>(self, other: U) -> Intersection<Self, U::IntoInfiniteIter>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+        U: IntoInfiniteIterator<Item = Self::Item>,
+    {
+        Intersection::new(self, other.into_infinite_iter())
+    }
+
+    /// Yield the items of this iterator not present in another infinite iterator,
+    /// both strictly increasing, in increasing order.
+    ///
+    /// Infinitely many items of this iterator must be absent from the other,
+    /// otherwise the resulting iterator will loop forever
+    /// looking for the next item.
+    /// Each item may require pulling arbitrarily many items from the other iterator
+    /// until one at least as large is found.
+    ///
+    /// # Examples
+    ///
+    /// Odd numbers that are not multiples of three:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (1..).step_by(2).difference((0..).step_by(3));
+    /// assert_eq!(iter.next_array(), [1, 5, 7, 11, 13, 17]);
+    /// ```
+    fn difference<U>(self, other: U) -> Difference<Self, U::IntoInfiniteIter>
+    where
+        Self: Sized,
+        Self::Item: Ord,
+        U: IntoInfiniteIterator<Item = Self::Item>,
+    {
+        Difference::new(self, other.into_infinite_iter())
+    }
+
+    /// Create an iterator over every pair of an item of this iterator
+    /// and an item of another infinite iterator.
+    ///
+    /// Unlike a nested loop,
+    /// which would never get past the first item of this iterator,
+    /// pairs are enumerated by anti-diagonals:
+    /// first the pair of indices `(0, 0)`,
+    /// then `(0, 1)` and `(1, 0)`,
+    /// then `(0, 2)`, `(1, 1)` and `(2, 0)`, and so on,
+    /// so every pair is eventually produced.
+    /// The items of both iterators are cached as they are produced,
+    /// and cloned into every pair they appear in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut pairs = (0..).diagonal_product(b'a'..).map_infinite(|(n, c)| (n, char::from(c)));
+    /// assert_eq!(
+    ///     pairs.next_array(),
+    ///     [(0, 'a'), (0, 'b'), (1, 'a'), (0, 'c'), (1, 'b'), (2, 'a')],
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn diagonal_product<U>(self, other: U) -> crate::DiagonalProduct<Self, U::IntoInfiniteIter>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+        U: IntoInfiniteIterator,
+        U::Item: Clone,
+    {
+        crate::DiagonalProduct::new(self, other.into_infinite_iter())
+    }
+
+    /// Like [`Iterator::chain`],
+    /// but guaranteeing the result is an [`InfiniteIterator`]
+    /// even though the second iterator may be finite.
+    ///
+    /// Since `self` never ends,
+    /// iterating forwards never reaches the second iterator.
+    /// If the second iterator is infinite and `self` is not,
+    /// use [`Iterator::chain`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (1..).chain_infinite([0]);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// assert_eq!(iter.next_infinite(), 2);
+    /// ```
+    fn chain_infinite<U>(self, other: U) -> ChainInfinite<Self, U::IntoIter>
+    where
+        Self: Sized,
+        U: IntoIterator<Item = Self::Item>,
+    {
+        ChainInfinite::new(self, other.into_iter())
+    }
+
+    /// Like [`Iterator::take`],
+    /// but returning an [`ExactSizeIterator`]
+    /// since there are always at least `n` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let iter = (0..).step_by(3).take_exact(4);
+    /// assert_eq!(iter.len(), 4);
+    /// assert_eq!(iter.collect::<Vec<_>>(), [0, 3, 6, 9]);
+    /// ```
+    fn take_exact(self, n: usize) -> TakeExact<Self>
+    where
+        Self: Sized,
+    {
+        TakeExact::new(self, n)
+    }
+
+    /// Collect exactly `n` items of this iterator into a collection.
+    ///
+    /// This is equivalent to `self.take_exact(n).collect()`,
+    /// so collections like `Vec` can use the exact length
+    /// to allocate up front.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let mut iter = (0..).map(|n| n * 2);
+    /// let first: Vec<_> = iter.collect_n(3);
+    /// assert_eq!(first, [0, 2, 4]);
+    ///
+    /// let rest: String = (b'a'..).map(char::from).collect_n(4);
+    /// assert_eq!(rest, "abcd");
+    /// ```
+    fn collect_n<C>(&mut self, n: usize) -> C
+    where
+        C: FromIterator<Self::Item>,
+    {
+        self.take_exact(n).collect()
+    }
+
+    /// Append exactly `n` items of this iterator to an existing collection.
+    ///
+    /// With the `nightly` feature enabled,
+    /// space for the items is reserved up front
+    /// using [`Extend::extend_reserve`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let mut ids = vec![100, 101];
+    /// let mut source = 0..;
+    /// source.extend_n(&mut ids, 3);
+    /// assert_eq!(ids, [100, 101, 0, 1, 2]);
+    /// source.extend_n(&mut ids, 1);
+    /// assert_eq!(ids, [100, 101, 0, 1, 2, 3]);
+    /// ```
+    ///
+    /// [`Extend::extend_reserve`]: https://doc.rust-lang.org/nightly/core/iter/trait.Extend.html#method.extend_reserve
+    fn extend_n<C>(&mut self, collection: &mut C, n: usize)
+    where
+        C: ?Sized + Extend<Self::Item>,
+    {
+        #[cfg(feature = "nightly")]
+        collection.extend_reserve(n);
+        collection.extend(self.take_exact(n));
+    }
+
+    /// Like [`Iterator::enumerate`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = core::iter::repeat('a').enumerate_infinite();
+    /// assert_eq!(iter.next_infinite(), (0, 'a'));
+    /// assert_eq!(iter.next_infinite(), (1, 'a'));
+    /// ```
+    fn enumerate_infinite(self) -> iter::Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        self.enumerate()
+    }
+
+    /// Like [`Iterator::step_by`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the given step is `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).step_by_infinite(5);
+    /// assert_eq!(iter.next_infinite(), 0);
+    /// assert_eq!(iter.next_infinite(), 5);
+    /// ```
+    fn step_by_infinite(self, step: usize) -> iter::StepBy<Self>
+    where
+        Self: Sized,
+    {
+        self.step_by(step)
+    }
+
+    /// Like [`Iterator::skip`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).skip_infinite(3);
+    /// assert_eq!(iter.next_infinite(), 3);
+    /// ```
+    fn skip_infinite(self, n: usize) -> iter::Skip<Self>
+    where
+        Self: Sized,
+    {
+        self.skip(n)
+    }
+
+    /// Like [`Iterator::skip_while`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).skip_while_infinite(|&num| num < 10);
+    /// assert_eq!(iter.next_infinite(), 10);
+    /// ```
+    fn skip_while_infinite<P>(self, predicate: P) -> iter::SkipWhile<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.skip_while(predicate)
+    }
+
+    /// Like [`Iterator::inspect`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut seen = Vec::new();
+    /// let mut iter = (0..).inspect_infinite(|&num| seen.push(num));
+    /// iter.next_infinite();
+    /// iter.next_infinite();
+    /// drop(iter);
+    /// assert_eq!(seen, [0, 1]);
+    /// ```
+    fn inspect_infinite<F>(self, f: F) -> iter::Inspect<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item),
+    {
+        self.inspect(f)
+    }
+
+    /// Call a closure with the index and a reference to
+    /// every `n`th item (those at indices `0`, `n`, `2 * n`, …)
+    /// before yielding it,
+    /// passing every item through unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// Progress reporting in a long-running loop:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut reports = Vec::new();
+    /// let mut iter = (0..).map_infinite(|n| n * n).inspect_every(1000, |index, &item| {
+    ///     reports.push(format!("item {index} is {item}"));
+    /// });
+    /// iter.advance_infinite_by(2500);
+    /// drop(iter);
+    /// assert_eq!(
+    ///     reports,
+    ///     [
+    ///         "item 0 is 0",
+    ///         "item 1000 is 1000000",
+    ///         "item 2000 is 4000000",
+    ///     ],
+    /// );
+    /// ```
+    fn inspect_every<F>(self, n: usize, f: F) -> InspectEvery<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(usize, &Self::Item),
+    {
+        InspectEvery::new(self, n, f)
+    }
+
+    /// Like [`Iterator::flat_map`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// The closure must return a non-empty iterator infinitely often,
+    /// otherwise the resulting iterator will loop forever
+    /// looking for the next item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (1..).flat_map_infinite(|num| [num; 2]);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// assert_eq!(iter.next_infinite(), 2);
+    /// ```
+    fn flat_map_infinite<U, F>(self, f: F) -> iter::FlatMap<Self, U, F>
+    where
+        Self: Sized,
+        U: IntoIterator,
+        F: FnMut(Self::Item) -> U,
+    {
+        self.flat_map(f)
+    }
+
+    /// Like [`Iterator::flatten`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// Infinitely many of the inner iterators must be non-empty,
+    /// otherwise the resulting iterator will loop forever
+    /// looking for the next item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = core::iter::repeat([1, 2]).flatten_infinite();
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// assert_eq!(iter.next_infinite(), 2);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// ```
+    fn flatten_infinite(self) -> iter::Flatten<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoIterator,
+    {
+        self.flatten()
+    }
+
+    /// Like [`Iterator::peekable`],
+    /// but returning an [`InfinitePeekable`]
+    /// whose peeking methods return references directly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).peekable_infinite();
+    /// assert_eq!(*iter.peek(), 0);
+    /// assert_eq!(iter.next_infinite(), 0);
+    /// ```
+    fn peekable_infinite(self) -> InfinitePeekable<Self>
+    where
+        Self: Sized,
+    {
+        InfinitePeekable::new(self)
+    }
+
+    /// Create an iterator that can peek at the next `N` items.
+    ///
+    /// The first `N` items are pulled from this iterator immediately
+    /// to fill the lookahead buffer.
+    ///
+    /// # Examples
+    ///
+    /// A parser deciding what to do based on the next two tokens:
+    ///
+    /// ```
+    /// use infinite_iterator::{cycle_array, InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut tokens = cycle_array(&["let", "x", "=", "1", ";"])
+    ///     .copied()
+    ///     .lookahead::<2>();
+    /// if *tokens.peek_ahead(0) == "let" && *tokens.peek_ahead(1) == "x" {
+    ///     tokens.next_infinite();
+    /// }
+    /// assert_eq!(tokens.next_infinite(), "x");
+    /// ```
+    fn lookahead<const N: usize>(self) -> Lookahead<Self, N>
+    where
+        Self: Sized,
+    {
+        Lookahead::new(self)
+    }
+
+    /// Create an iterator that can peek arbitrarily far ahead,
+    /// buffering items as necessary.
+    ///
+    /// See [`MultiPeek::peek_infinite`](crate::MultiPeek::peek_infinite).
+    ///
+    /// # Examples
+    ///
+    /// Speculatively scanning ahead for a token:
+    ///
+    /// ```
+    /// use infinite_iterator::{cycle_array, InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut tokens = cycle_array(&["a", "b", "c", ";"]).copied().multipeek();
+    /// let mut len = 0;
+    /// while *tokens.peek_infinite() != ";" {
+    ///     len += 1;
+    /// }
+    /// assert_eq!(len, 3);
+    /// assert_eq!(tokens.next_infinite(), "a");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn multipeek(self) -> crate::MultiPeek<Self>
+    where
+        Self: Sized,
+    {
+        crate::MultiPeek::new(self)
+    }
+
+    /// Split this iterator into two handles
+    /// that each yield every item.
+    ///
+    /// Items yielded by one handle but not yet by the other are buffered,
+    /// so the handles can be consumed at different rates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let (mut a, mut b) = (0..).tee();
+    /// assert_eq!(a.next_infinite(), 0);
+    /// assert_eq!(a.next_infinite(), 1);
+    /// assert_eq!(b.next_infinite(), 0);
+    /// assert_eq!(a.next_infinite(), 2);
+    /// assert_eq!(b.next_infinite(), 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn tee(self) -> (crate::Tee<Self>, crate::Tee<Self>)
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        let [a, b] = crate::Tee::new(self);
+        (a, b)
+    }
+
+    /// Split this iterator into `N` handles
+    /// that each yield every item.
+    ///
+    /// See [`tee`](Self::tee).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let [mut a, mut b, mut c] = (0..).tee_n();
+    /// assert_eq!(a.next_infinite(), 0);
+    /// assert_eq!(b.next_infinite(), 0);
+    /// assert_eq!(c.next_infinite(), 0);
+    /// assert_eq!(c.next_infinite(), 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn tee_n<const N: usize>(self) -> [crate::Tee<Self>; N]
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        crate::Tee::new(self)
+    }
+
+    /// Like [`tee`](Self::tee),
+    /// but the handles can be sent to and consumed from different threads.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let (mut a, mut b) = (0_u32..).tee_sync();
+    /// let thread = std::thread::spawn(move || (b.next_infinite(), b.next_infinite()));
+    /// assert_eq!(a.next_infinite(), 0);
+    /// assert_eq!(thread.join().unwrap(), (0, 1));
+    /// ```
+    #[cfg(feature = "std")]
+    fn tee_sync(self) -> (crate::TeeSync<Self>, crate::TeeSync<Self>)
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        let [a, b] = crate::TeeSync::new(self);
+        (a, b)
+    }
+
+    /// Like [`tee_n`](Self::tee_n),
+    /// but the handles can be sent to and consumed from different threads.
+    #[cfg(feature = "std")]
+    fn tee_sync_n<const N: usize>(self) -> [crate::TeeSync<Self>; N]
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        crate::TeeSync::new(self)
+    }
+
+    /// Drive this iterator on a background thread,
+    /// sending every item to each of `n` handles.
+    ///
+    /// Each handle buffers up to `capacity` items.
+    /// When any handle's buffer is full,
+    /// the background thread blocks until that handle catches up,
+    /// so the slowest consumer limits the rate of all of them.
+    /// A handle that is dropped stops receiving items,
+    /// and the background thread exits once every handle has been dropped.
+    ///
+    /// If the iterator panics,
+    /// the handles will panic once they have yielded every buffered item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let workers: Vec<_> = (0_u64..)
+    ///     .broadcast(3, 16)
+    ///     .into_iter()
+    ///     .map(|mut samples| {
+    ///         std::thread::spawn(move || (0..10).map(|_| samples.next_infinite()).sum::<u64>())
+    ///     })
+    ///     .collect();
+    ///
+    /// for worker in workers {
+    ///     assert_eq!(worker.join().unwrap(), 45);
+    /// }
+    /// ```
+    #[cfg(feature = "std")]
+    fn broadcast(self, n: usize, capacity: usize) -> std::vec::Vec<crate::Broadcast<Self::Item>>
+    where
+        Self: Sized + Send + 'static,
+        Self::Item: Clone + Send + 'static,
+    {
+        crate::Broadcast::spawn(self, n, capacity)
+    }
+
+    /// Cache every item of this iterator to allow random access by index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let mut evens = (0..).step_by(2).memoize();
+    /// evens.materialize(5);
+    /// assert_eq!(evens[5], 10);
+    /// assert_eq!(evens[0], 0);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn memoize(self) -> crate::Memoized<Self>
+    where
+        Self: Sized,
+    {
+        crate::Memoized::new(self)
+    }
+
+    /// Group the items of this iterator into consecutive [`Vec`]s
+    /// of length `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// Batching requests:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut batches = (0..).chunks(3);
+    /// assert_eq!(batches.next_infinite(), [0, 1, 2]);
+    /// assert_eq!(batches.next_infinite(), [3, 4, 5]);
+    /// ```
+    ///
+    /// [`Vec`]: alloc::vec::Vec
+    #[cfg(feature = "alloc")]
+    fn chunks(self, size: usize) -> crate::Chunks<Self>
+    where
+        Self: Sized,
+    {
+        crate::Chunks::new(self, size)
+    }
+
+    /// Group the items of this iterator into consecutive arrays of length `N`.
+    ///
+    /// This is a stable, allocation-free counterpart of
+    /// the unstable `Iterator::array_chunks`.
+    /// Since the underlying iterator never ends,
+    /// there is never a remainder.
+    ///
+    /// # Examples
+    ///
+    /// Decoding interleaved stereo samples:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut frames = (0..).array_chunks_infinite::<2>();
+    /// assert_eq!(frames.next_infinite(), [0, 1]);
+    /// assert_eq!(frames.next_infinite(), [2, 3]);
+    /// ```
+    fn array_chunks_infinite<const N: usize>(self) -> ArrayChunksInfinite<Self, N>
+    where
+        Self: Sized,
+    {
+        ArrayChunksInfinite::new(self)
+    }
+
+    /// Create an iterator over overlapping windows
+    /// of the last `N` items of this iterator.
+    ///
+    /// The first window is yielded once `N` items have been pulled;
+    /// after that each window shares all but one item with the previous one.
+    /// Items are cloned into every window they appear in.
+    ///
+    /// # Examples
+    ///
+    /// A moving average:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut averages = (1..)
+    ///     .windows::<3>()
+    ///     .map_infinite(|window| window.iter().sum::<u32>() / 3);
+    /// assert_eq!(averages.next_infinite(), 2);
+    /// assert_eq!(averages.next_infinite(), 3);
+    /// assert_eq!(averages.next_infinite(), 4);
+    /// ```
+    fn windows<const N: usize>(self) -> Windows<Self, N>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Windows::new(self)
+    }
+
+    /// Create an iterator over overlapping tuples of consecutive items.
+    ///
+    /// The size of the tuples is chosen by the type `T`,
+    /// which can be a tuple of two, three or four elements;
+    /// see [`HomogeneousTuple`].
+    /// Items are cloned into every tuple they appear in.
+    ///
+    /// # Examples
+    ///
+    /// Detecting local maxima:
+    ///
+    /// ```
+    /// use infinite_iterator::{cycle_array, InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut peaks = cycle_array(&[1, 3, 2, 4, 5, 1])
+    ///     .copied()
+    ///     .tuple_windows()
+    ///     .filter_infinite(|&(a, b, c)| a < b && b > c)
+    ///     .map_infinite(|(_, b, _)| b);
+    /// assert_eq!(peaks.next_infinite(), 3);
+    /// assert_eq!(peaks.next_infinite(), 5);
+    /// assert_eq!(peaks.next_infinite(), 3);
+    /// ```
+    fn tuple_windows<T>(self) -> TupleWindows<Self, T>
+    where
+        Self: Sized,
+        T: HomogeneousTuple<Item = Self::Item> + Clone,
+    {
+        TupleWindows::new(self)
+    }
+
+    /// Create an iterator over overlapping pairs of consecutive items.
+    ///
+    /// This is [`tuple_windows`](Self::tuple_windows) specialized to pairs.
+    ///
+    /// # Examples
+    ///
+    /// Computing differences between successive items:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut gaps = (1..)
+    ///     .map_infinite(|n: u32| n * n)
+    ///     .pairwise()
+    ///     .map_infinite(|(prev, current)| current - prev);
+    /// assert_eq!(gaps.next_infinite(), 3);
+    /// assert_eq!(gaps.next_infinite(), 5);
+    /// assert_eq!(gaps.next_infinite(), 7);
+    /// ```
+    fn pairwise(self) -> TupleWindows<Self, (Self::Item, Self::Item)>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        TupleWindows::new(self)
+    }
+
+    /// Group the items of this iterator into consecutive,
+    /// non-overlapping tuples.
+    ///
+    /// The size of the tuples is chosen by the type `T`,
+    /// as with [`tuple_windows`](Self::tuple_windows).
+    /// Since the underlying iterator never ends,
+    /// there is never a remainder.
+    ///
+    /// # Examples
+    ///
+    /// Decoding interleaved RGB channels:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut pixels = (0_u8..).tuples();
+    /// let (r, g, b) = pixels.next_infinite();
+    /// assert_eq!((r, g, b), (0, 1, 2));
+    /// assert_eq!(pixels.next_infinite(), (3, 4, 5));
+    /// ```
+    fn tuples<T>(self) -> Tuples<Self, T>
+    where
+        Self: Sized,
+        T: HomogeneousTuple<Item = Self::Item>,
+    {
+        Tuples::new(self)
+    }
+
+    /// Group maximal runs of consecutive items
+    /// for which `key` returns equal values,
+    /// yielding each run's key alongside a [`Vec`] of its items.
+    ///
+    /// The key must change infinitely often,
+    /// otherwise the resulting iterator will loop forever
+    /// looking for the end of the current run.
+    ///
+    /// # Examples
+    ///
+    /// Segmenting a stream of events by the hour they happened in:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut hours = (0..).step_by(25).chunk_by(|minutes| minutes / 60);
+    /// assert_eq!(hours.next_infinite(), (0, vec![0, 25, 50]));
+    /// assert_eq!(hours.next_infinite(), (1, vec![75, 100]));
+    /// assert_eq!(hours.next_infinite(), (2, vec![125, 150, 175]));
+    /// ```
+    ///
+    /// [`Vec`]: alloc::vec::Vec
+    #[cfg(feature = "alloc")]
+    fn chunk_by<K, F>(self, key: F) -> crate::ChunkBy<Self, K, F>
+    where
+        Self: Sized,
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        crate::ChunkBy::new(self, key)
+    }
+
+    /// Remove consecutive duplicate items.
+    ///
+    /// The iterator must not become eventually constant,
+    /// otherwise the resulting iterator will loop forever
+    /// looking for the next distinct item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).map_infinite(|n| n / 3).dedup();
+    /// assert_eq!(iter.next_infinite(), 0);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// assert_eq!(iter.next_infinite(), 2);
+    /// ```
+    fn dedup(self) -> Dedup<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        DedupBy::new(self, PartialEq::eq)
+    }
+
+    /// Remove consecutive items that `same_bucket` considers duplicates.
+    ///
+    /// `same_bucket` is passed the item that will be yielded
+    /// and a later item that might be removed.
+    /// It must return `false` infinitely often,
+    /// otherwise the resulting iterator will loop forever
+    /// looking for the next item.
+    ///
+    /// # Examples
+    ///
+    /// Ignoring jitter in a sensor reading:
+    ///
+    /// ```
+    /// use infinite_iterator::{cycle_array, InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut readings = cycle_array(&[10, 11, 10, 20, 21, 30])
+    ///     .copied()
+    ///     .dedup_by(|a: &i32, b| (a - b).abs() <= 1);
+    /// assert_eq!(readings.next_infinite(), 10);
+    /// assert_eq!(readings.next_infinite(), 20);
+    /// assert_eq!(readings.next_infinite(), 30);
+    /// assert_eq!(readings.next_infinite(), 10);
+    /// ```
+    fn dedup_by<F>(self, same_bucket: F) -> DedupBy<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        DedupBy::new(self, same_bucket)
+    }
+
+    /// Compress maximal runs of equal consecutive items
+    /// into pairs of the item and the length of the run.
+    ///
+    /// The iterator must not become eventually constant,
+    /// otherwise the resulting iterator will loop forever
+    /// looking for the end of the current run.
+    ///
+    /// # Examples
+    ///
+    /// Compressing telemetry samples:
+    ///
+    /// ```
+    /// use infinite_iterator::{cycle_array, InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut runs = cycle_array(&[0, 0, 0, 5, 5, 0, 7])
+    ///     .copied()
+    ///     .run_length_encode();
+    /// assert_eq!(runs.next_infinite(), (0, 3));
+    /// assert_eq!(runs.next_infinite(), (5, 2));
+    /// assert_eq!(runs.next_infinite(), (0, 1));
+    /// assert_eq!(runs.next_infinite(), (7, 1));
+    /// assert_eq!(runs.next_infinite(), (0, 3));
+    /// ```
+    fn run_length_encode(self) -> RunLengthEncode<Self>
+    where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        RunLengthEncode::new(self)
+    }
+
+    /// Expand pairs of an item and a count
+    /// into that many consecutive copies of the item.
+    ///
+    /// This is the inverse of [`run_length_encode`](Self::run_length_encode).
+    /// The count can be a `usize` or a [`NonZeroUsize`].
+    /// Runs with a count of zero are skipped,
+    /// so there must be infinitely many runs with a non-zero count,
+    /// otherwise the resulting iterator will loop forever
+    /// looking for the next item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (1_usize..)
+    ///     .map_infinite(|n| (n, n))
+    ///     .run_length_decode();
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// assert_eq!(iter.next_infinite(), 2);
+    /// assert_eq!(iter.next_infinite(), 2);
+    /// assert_eq!(iter.next_infinite(), 3);
+    /// ```
+    ///
+    /// Round-tripping through [`run_length_encode`](Self::run_length_encode):
+    ///
+    /// ```
+    /// use infinite_iterator::{cycle_array, InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let samples = cycle_array(&['a', 'a', 'b', 'c', 'c', 'c']).copied();
+    /// let mut decoded = samples.clone().run_length_encode().run_length_decode();
+    /// assert_eq!(decoded.next_array::<12>(), samples.clone().next_array::<12>());
+    /// ```
+    ///
+    /// [`NonZeroUsize`]: core::num::NonZeroUsize
+    fn run_length_decode<T, N>(self) -> RunLengthDecode<Self, T>
+    where
+        Self: Sized + InfiniteIterator<Item = (T, N)>,
+        T: Clone,
+        N: Into<usize>,
+    {
+        RunLengthDecode::new(self)
+    }
+
+    /// Create an iterator over the differences between consecutive items.
+    ///
+    /// The first item is yielded as-is;
+    /// use [`delta_encode_from`](Self::delta_encode_from)
+    /// to compare it against a baseline instead.
+    /// `policy` decides what happens when a difference overflows,
+    /// such as in a decreasing sequence of unsigned integers;
+    /// see the [`overflow`](crate::overflow) module.
+    ///
+    /// # Examples
+    ///
+    /// Compressing a monotone counter:
+    ///
+    /// ```
+    /// use infinite_iterator::{overflow, InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut deltas = (1000..).step_by(10).delta_encode(overflow::Wrapping);
+    /// assert_eq!(deltas.next_infinite(), 1000);
+    /// assert_eq!(deltas.next_infinite(), 10);
+    /// assert_eq!(deltas.next_infinite(), 10);
+    /// ```
+    ///
+    /// Choosing what happens on overflow:
+    ///
+    /// ```
+    /// use infinite_iterator::{cycle_array, overflow, InfiniteIteratorExt};
+    ///
+    /// let samples = cycle_array(&[5_u8, 3]).copied();
+    ///
+    /// let mut wrapping = samples.clone().delta_encode(overflow::Wrapping);
+    /// assert_eq!(wrapping.next_array(), [5, 254, 2, 254]);
+    ///
+    /// let mut saturating = samples.clone().delta_encode(overflow::Saturating);
+    /// assert_eq!(saturating.next_array(), [5, 0, 2, 0]);
+    ///
+    /// let mut checked = samples.delta_encode(overflow::Checked);
+    /// assert_eq!(checked.next_array(), [Some(5), None, None, None]);
+    /// ```
+    fn delta_encode<P>(self, policy: P) -> DeltaEncode<Self, P>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+        P: Policy,
+    {
+        DeltaEncode::new(self, None, policy)
+    }
+
+    /// Like [`delta_encode`](Self::delta_encode),
+    /// but yielding the first item's difference from `baseline`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{overflow, InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut deltas = (1..).map_infinite(|n| n * n).delta_encode_from(0, overflow::Checked);
+    /// assert_eq!(deltas.next_infinite(), Some(1));
+    /// assert_eq!(deltas.next_infinite(), Some(3));
+    /// assert_eq!(deltas.next_infinite(), Some(5));
+    /// ```
+    ///
+    /// Round-tripping through [`delta_decode`](Self::delta_decode):
+    ///
+    /// ```
+    /// use infinite_iterator::{cycle_array, overflow, InfiniteIteratorExt};
+    ///
+    /// let samples = cycle_array(&[7_u16, 3, 65535, 0]).copied();
+    /// let mut decoded = samples
+    ///     .clone()
+    ///     .delta_encode_from(0, overflow::Wrapping)
+    ///     .delta_decode(0, overflow::Wrapping);
+    /// assert_eq!(decoded.next_array::<8>(), samples.clone().next_array::<8>());
+    /// ```
+    fn delta_encode_from<P>(self, baseline: Self::Item, policy: P) -> DeltaEncode<Self, P>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+        P: Policy,
+    {
+        DeltaEncode::new(self, Some(baseline), policy)
+    }
+
+    /// Create an iterator over the running sum of this iterator's items,
+    /// starting from `initial`.
+    ///
+    /// This is the inverse of [`delta_encode_from`](Self::delta_encode_from).
+    /// `policy` decides what happens when the sum overflows;
+    /// see the [`overflow`](crate::overflow) module.
+    ///
+    /// # Examples
+    ///
+    /// Reconstructing a counter from its deltas:
+    ///
+    /// ```
+    /// use infinite_iterator::{overflow, InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut counter = core::iter::repeat(10_u32).delta_decode(1000, overflow::Wrapping);
+    /// assert_eq!(counter.next_infinite(), 1010);
+    /// assert_eq!(counter.next_infinite(), 1020);
+    /// ```
+    ///
+    /// Choosing what happens on overflow:
+    ///
+    /// ```
+    /// use infinite_iterator::{overflow, InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let deltas = core::iter::repeat(100_u8);
+    ///
+    /// let mut wrapping = deltas.clone().delta_decode(0, overflow::Wrapping);
+    /// assert_eq!(wrapping.next_array(), [100, 200, 44]);
+    ///
+    /// let mut saturating = deltas.clone().delta_decode(0, overflow::Saturating);
+    /// assert_eq!(saturating.next_array(), [100, 200, 255]);
+    ///
+    /// let mut checked = deltas.delta_decode(0, overflow::Checked);
+    /// assert_eq!(checked.next_array(), [Some(100), Some(200), None, None]);
+    /// ```
+    fn delta_decode<P>(self, initial: Self::Item, policy: P) -> DeltaDecode<Self, P>
+    where
+        Self: Sized,
+        Self::Item: Integer,
+        P: Policy,
+    {
+        DeltaDecode::new(self, initial, policy)
+    }
+
+    /// Like [`Iterator::scan`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// The closure returns the item directly rather than an `Option`,
+    /// so it cannot end the iterator.
+    ///
+    /// # Examples
+    ///
+    /// A running maximum:
+    ///
+    /// ```
+    /// use infinite_iterator::{cycle_array, InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut max = cycle_array(&[3, 1, 4, 1, 5])
+    ///     .copied()
+    ///     .scan_infinite(0, |max, n| {
+    ///         *max = n.max(*max);
+    ///         *max
+    ///     });
+    /// assert_eq!(max.next_array(), [3, 3, 4, 4, 5, 5]);
+    /// assert_eq!(*max.state(), 5);
+    /// ```
+    fn scan_infinite<S, B, F>(self, initial_state: S, f: F) -> ScanInfinite<Self, S, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut S, Self::Item) -> B,
+    {
+        ScanInfinite::new(self, initial_state, f)
+    }
+
+    /// Like [`Iterator::cycle`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// Since the underlying iterator never ends,
+    /// this behaves exactly like the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (1..).step_by(2).cycle_infinite();
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// assert_eq!(iter.next_infinite(), 3);
+    /// ```
+    fn cycle_infinite(self) -> iter::Cycle<Self>
+    where
+        Self: Sized + Clone,
+    {
+        self.cycle()
+    }
+
+    /// Like [`Iterator::fuse`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// Since the underlying iterator never ends,
+    /// this behaves exactly like the underlying iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = core::iter::repeat(7).fuse_infinite();
+    /// assert_eq!(iter.next_infinite(), 7);
+    /// assert_eq!(iter.next_infinite(), 7);
+    /// ```
+    fn fuse_infinite(self) -> iter::Fuse<Self>
+    where
+        Self: Sized,
+    {
+        self.fuse()
+    }
+
+    /// Like [`Iterator::cloned`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let s = String::from("hello");
+    /// let mut iter = core::iter::repeat(&s).cloned_infinite();
+    /// assert_eq!(iter.next_infinite(), "hello");
+    /// ```
+    fn cloned_infinite<'item, T>(self) -> iter::Cloned<Self>
+    where
+        Self: Sized + InfiniteIterator<Item = &'item T>,
+        T: 'item + Clone,
+    {
+        self.cloned()
+    }
+
+    /// Like [`Iterator::copied`],
+    /// but guaranteeing the result is an [`InfiniteIterator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = core::iter::repeat(&5).copied_infinite();
+    /// assert_eq!(iter.next_infinite(), 5);
+    /// ```
+    fn copied_infinite<'item, T>(self) -> iter::Copied<Self>
+    where
+        Self: Sized + InfiniteIterator<Item = &'item T>,
+        T: 'item + Copy,
+    {
+        self.copied()
+    }
+
+    /// Pull the next `N` items into an array.
+    ///
+    /// This is the infinite counterpart of the unstable `Iterator::next_chunk`,
+    /// without an error case because the iterator never ends.
+    /// It goes through [`InfiniteIterator::next_chunk_into`],
+    /// so when called on a trait object such as `&mut dyn InfiniteIterator`
+    /// it makes a single dynamic call for the whole chunk.
+    /// If the iterator panics partway through,
+    /// the items pulled so far are leaked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let mut iter = 0..;
+    /// assert_eq!(iter.next_array(), [0, 1, 2]);
+    /// assert_eq!(iter.next_array::<2>(), [3, 4]);
+    /// ```
+    #[doc(alias = "next_chunk", alias = "next_chunk_infinite")]
+    fn next_array<const N: usize>(&mut self) -> [Self::Item; N] {
+        // SAFETY: An array of `MaybeUninit`s does not need initialization.
+        let mut buffer: [MaybeUninit<Self::Item>; N] =
+            unsafe { MaybeUninit::uninit().assume_init() };
+        let mut chunk = ChunkWriter::new(&mut buffer);
+        self.next_chunk_into(&mut chunk);
+        let filled = chunk.into_filled();
+        // SAFETY: `into_filled` checked that all `N` elements are initialized,
+        // and `[T]` of length `N` has the same layout as `[T; N]`.
+        // The items are moved out of `buffer`,
+        // which does not drop them again because it holds `MaybeUninit`s.
+        unsafe { filled.as_ptr().cast::<[Self::Item; N]>().read() }
+    }
+
+    /// Overwrite every element of a slice with successive items of this iterator.
+    ///
+    /// # Examples
+    ///
+    /// Refilling an audio buffer:
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let mut samples = (0..).map(|n| if n % 2 == 0 { 1.0 } else { -1.0 });
+    /// let mut buffer = [0.0; 4];
+    /// samples.fill_slice(&mut buffer);
+    /// assert_eq!(buffer, [1.0, -1.0, 1.0, -1.0]);
+    /// ```
+    fn fill_slice(&mut self, slice: &mut [Self::Item]) {
+        for slot in slice {
+            *slot = self.next_infinite();
+        }
+    }
+
+    /// Initialize every element of an uninitialized slice
+    /// with successive items of this iterator,
+    /// returning the now-initialized slice.
+    ///
+    /// The returned slice borrows the same memory as the input,
+    /// so the items are not dropped when it goes out of scope;
+    /// this is the same behaviour as [`MaybeUninit::write`].
+    /// If the iterator panics partway through,
+    /// the items written so far are leaked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::mem::MaybeUninit;
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let mut buffer = [MaybeUninit::<u32>::uninit(); 3];
+    /// let filled = (1..).map(|n| n * n).fill_uninit(&mut buffer);
+    /// assert_eq!(filled, [1, 4, 9]);
+    /// ```
+    ///
+    /// [`MaybeUninit::write`]: core::mem::MaybeUninit::write
+    fn fill_uninit<'a>(
+        &mut self,
+        slice: &'a mut [MaybeUninit<Self::Item>],
+    ) -> &'a mut [Self::Item] {
+        let mut chunk = ChunkWriter::new(slice);
+        self.next_chunk_into(&mut chunk);
+        chunk.into_filled()
+    }
+
+    /// Like [`Iterator::nth`],
+    /// but returning the item directly because the iterator never ends.
+    ///
+    /// This forwards to the underlying iterator's [`Iterator::nth`],
+    /// so iterators like [`RangeFrom`] can skip ahead in constant time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = (0..).step_by(5);
+    /// assert_eq!(iter.nth_infinite(2), 10);
+    /// assert_eq!(iter.next_infinite(), 15);
+    /// ```
+    ///
+    /// [`RangeFrom`]: core::ops::RangeFrom
+    fn nth_infinite(&mut self, n: usize) -> Self::Item {
+        self.nth(n).unwrap()
+    }
+
+    /// Advance the iterator by exactly `n` items.
+    ///
+    /// Unlike the unstable `Iterator::advance_by`,
+    /// this cannot fail because the iterator never ends.
+    /// It forwards to the underlying iterator's `advance_by`
+    /// when the `nightly` feature is enabled,
+    /// and to [`Iterator::nth`] otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = 0..;
+    /// iter.advance_infinite_by(1_000_000);
+    /// assert_eq!(iter.next_infinite(), 1_000_000);
+    /// ```
+    fn advance_infinite_by(&mut self, n: usize) {
+        #[cfg(feature = "nightly")]
+        {
+            let _ = self.advance_by(n);
+        }
+        #[cfg(not(feature = "nightly"))]
+        if let Some(last) = n.checked_sub(1) {
+            self.nth(last);
+        }
+    }
+
+    /// Fold items into an accumulator until the closure decides to stop,
+    /// returning the value it stopped with.
+    ///
+    /// The closure returns [`ControlFlow::Continue`] with the new accumulator
+    /// to keep going, or [`ControlFlow::Break`] with the result to stop.
+    /// Unlike [`Iterator::fold`],
+    /// which would never return on an infinite iterator,
+    /// this returns as soon as the closure breaks.
+    /// If it never does,
+    /// this method loops forever.
+    ///
+    /// # Examples
+    ///
+    /// Finding how many items it takes for a running sum to exceed a limit:
+    ///
+    /// ```
+    /// use core::ops::ControlFlow;
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let count = (1..).fold_until((0, 0), |(sum, count), n| {
+    ///     if sum + n > 100 {
+    ///         ControlFlow::Break(count)
+    ///     } else {
+    ///         ControlFlow::Continue((sum + n, count + 1))
+    ///     }
+    /// });
+    /// assert_eq!(count, 13);
+    /// ```
+    ///
+    /// [`ControlFlow::Continue`]: core::ops::ControlFlow::Continue
+    /// [`ControlFlow::Break`]: core::ops::ControlFlow::Break
+    fn fold_until<Acc, B, F>(&mut self, init: Acc, f: F) -> B
+    where
+        Self: Sized,
+        F: FnMut(Acc, Self::Item) -> ControlFlow<B, Acc>,
+    {
+        match self.try_fold(init, f) {
+            ControlFlow::Break(result) => result,
+            ControlFlow::Continue(_) => unreachable!("infinite iterator ended"),
+        }
+    }
+
+    /// Fold exactly the next `n` items into an accumulator.
+    ///
+    /// This is like `self.take_exact(n).fold(init, f)`,
+    /// but uses the underlying iterator's [`Iterator::try_fold`].
+    ///
+    /// # Examples
+    ///
+    /// Summarizing a fixed-length prefix:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut samples = (1..).map(|n| n * 2);
+    /// assert_eq!(samples.fold_n(3, 0, |sum, n| sum + n), 12);
+    /// assert_eq!(samples.next_infinite(), 8);
+    /// ```
+    fn fold_n<Acc, F>(&mut self, n: usize, init: Acc, mut f: F) -> Acc
+    where
+        Self: Sized,
+        F: FnMut(Acc, Self::Item) -> Acc,
+    {
+        if n == 0 {
+            return init;
+        }
+        let mut remaining = n;
+        self.fold_until(init, |acc, item| {
+            let acc = f(acc, item);
+            remaining -= 1;
+            if remaining == 0 {
+                ControlFlow::Break(acc)
+            } else {
+                ControlFlow::Continue(acc)
+            }
+        })
+    }
+
+    /// Call a closure on exactly the next `n` items.
+    ///
+    /// Like [`fold_n`](Self::fold_n),
+    /// this drives the underlying iterator's [`Iterator::try_fold`],
+    /// which is often faster than calling
+    /// [`next_infinite`](InfiniteIterator::next_infinite) `n` times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// let mut iter = 0..;
+    /// let mut seen = Vec::new();
+    /// iter.for_each_n(3, |n| seen.push(n));
+    /// assert_eq!(seen, [0, 1, 2]);
+    /// assert_eq!(iter.next_infinite(), 3);
+    /// ```
+    fn for_each_n<F>(&mut self, n: usize, mut f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item),
+    {
+        self.fold_n(n, (), |(), item| f(item));
+    }
+
+    /// Find the cycle of an eventually periodic iterator,
+    /// returning `(mu, lambda)`:
+    /// the index of the first item of the cycle
+    /// and the length of the cycle.
+    ///
+    /// This uses Brent's algorithm,
+    /// which runs in constant memory
+    /// by stepping clones of the iterator.
+    /// Each item must be determined by the item before it,
+    /// as with [`successors_infinite`](crate::successors_infinite)
+    /// or a pseudorandom number generator,
+    /// since the cycle is found by comparing items.
+    /// If the iterator is not eventually periodic,
+    /// this method loops forever.
+    ///
+    /// # Examples
+    ///
+    /// A linear congruential generator:
+    ///
+    /// ```
+    /// use infinite_iterator::{successors_infinite, InfiniteIteratorExt};
+    ///
+    /// let lcg = successors_infinite(3_u32, |x| (x * 5 + 1) % 16);
+    /// assert_eq!(lcg.detect_cycle(), (0, 16));
+    ///
+    /// let tail_then_cycle = successors_infinite(10_u32, |&x| if x > 3 { x - 1 } else { x + 1 });
+    /// assert_eq!(tail_then_cycle.detect_cycle(), (6, 2));
+    /// ```
+    fn detect_cycle(&self) -> (usize, usize)
+    where
+        Self: Sized + Clone,
+        Self::Item: PartialEq,
+    {
+        // Find the cycle length, comparing each item against the item
+        // at the most recent power of two.
+        let mut iter = self.clone();
+        let mut tortoise = iter.next_infinite();
+        let mut hare = iter.next_infinite();
+        let mut power = 1;
+        let mut lambda = 1;
+        while tortoise != hare {
+            if power == lambda {
+                tortoise = hare;
+                power *= 2;
+                lambda = 0;
+            }
+            hare = iter.next_infinite();
+            lambda += 1;
+        }
+
+        // Find the start of the cycle using two iterators `lambda` items apart.
+        let mut tortoise_iter = self.clone();
+        let mut hare_iter = self.clone();
+        for _ in 0..lambda {
+            hare_iter.next_infinite();
+        }
+        let mut mu = 0;
+        while tortoise_iter.next_infinite() != hare_iter.next_infinite() {
+            mu += 1;
+        }
+
+        (mu, lambda)
+    }
+
+    /// Find the length of the cycle of an eventually periodic iterator.
+    ///
+    /// This is the second element of [`detect_cycle`](Self::detect_cycle),
+    /// and has the same requirements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{cycle_array, InfiniteIteratorExt};
+    ///
+    /// let iter = cycle_array(&['a', 'b', 'c']).copied();
+    /// assert_eq!(iter.period(), 3);
+    /// ```
+    fn period(&self) -> usize
+    where
+        Self: Sized + Clone,
+        Self::Item: PartialEq,
+    {
+        self.detect_cycle().1
+    }
+
+    /// Create a finite iterator over exactly one repetition
+    /// of the cycle of an eventually periodic iterator,
+    /// starting at the first item of the cycle.
+    ///
+    /// This has the same requirements as [`detect_cycle`](Self::detect_cycle).
+    ///
+    /// # Examples
+    ///
+    /// Materializing the repeating unit of a cyclic stream:
+    ///
+    /// ```
+    /// use infinite_iterator::{successors_infinite, InfiniteIteratorExt};
+    ///
+    /// let iter = successors_infinite(1_u32, |x| x * 10 % 7);
+    /// let unit: Vec<_> = iter.take_one_period().collect();
+    /// assert_eq!(unit, [1, 3, 2, 6, 4, 5]);
+    /// ```
+    fn take_one_period(&self) -> TakeExact<Self>
+    where
+        Self: Sized + Clone,
+        Self::Item: PartialEq,
+    {
+        let (mu, lambda) = self.detect_cycle();
+        let mut iter = self.clone();
+        for _ in 0..mu {
+            iter.next_infinite();
+        }
+        iter.take_exact(lambda)
+    }
+
+    /// Pull items until one is equal to an item seen before,
+    /// returning the index of its first occurrence,
+    /// the index of the repeat,
+    /// and the item itself.
+    ///
+    /// Every distinct item is stored in a hash map until the repeat is found.
+    /// If no item is ever repeated,
+    /// this method loops forever.
+    ///
+    /// # Examples
+    ///
+    /// Probing a pseudorandom sequence:
+    ///
+    /// ```
+    /// use infinite_iterator::{successors_infinite, InfiniteIteratorExt};
+    ///
+    /// let mut sequence = successors_infinite(7_u32, |x| (x * x + 1) % 101);
+    /// let (first, repeat, value) = sequence.first_repeat();
+    /// assert_eq!((first, repeat), (3, 12));
+    /// assert_eq!(value, 72);
+    /// ```
+    #[cfg(feature = "std")]
+    fn first_repeat(&mut self) -> (usize, usize, Self::Item)
+    where
+        Self::Item: Eq + core::hash::Hash,
+    {
+        use std::collections::hash_map::{Entry, HashMap};
+
+        let mut seen = HashMap::new();
+        let mut index = 0;
+        loop {
+            match seen.entry(self.next_infinite()) {
+                Entry::Occupied(entry) => {
+                    let (item, first) = entry.remove_entry();
+                    break (first, index, item);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(index);
+                }
+            }
+            index += 1;
+        }
+    }
+
+    /// Find the index of the first item satisfying `pred`,
+    /// given an iterator partitioned such that
+    /// every item satisfying `pred` comes after every item that does not.
+    ///
+    /// This uses an exponential search
+    /// via [`Memoized::partition_point`](crate::Memoized::partition_point),
+    /// calling `pred` far fewer times than a linear scan
+    /// when the partition point is far away.
+    /// If `pred` never returns `true`,
+    /// this method loops forever.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let powers = (0..).map(|n| 3_u64.pow(n));
+    /// assert_eq!(powers.partition_point_infinite(|&power| power >= 1_000), 7);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn partition_point_infinite<P>(self, pred: P) -> usize
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        self.memoize().partition_point(pred)
+    }
+
+    /// Find the first item greater than or equal to `value`
+    /// in an iterator whose items are in ascending order,
+    /// returning its index and the item.
+    ///
+    /// This uses an exponential search
+    /// via [`Memoized::find_sorted`](crate::Memoized::find_sorted).
+    /// If every item is less than `value`,
+    /// this method loops forever.
+    ///
+    /// # Examples
+    ///
+    /// Searching the triangular numbers:
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteIteratorExt;
+    ///
+    /// let triangular = (0_u64..).map(|n| n * (n + 1) / 2);
+    /// assert_eq!(triangular.find_sorted(&5000), (100, 5050));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn find_sorted(self, value: &Self::Item) -> (usize, Self::Item)
+    where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut memoized = self.memoize();
+        let (index, _) = memoized.find_sorted(value);
+        (index, memoized.into_cached().swap_remove(index))
+    }
+
+    /// Convert this iterator into an [`InfiniteLendingIterator`](crate::InfiniteLendingIterator).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIteratorExt, InfiniteLendingIterator};
+    ///
+    /// let mut iter = (0..).lending();
+    /// assert_eq!(iter.next_infinite(), 0);
+    /// ```
+    fn lending(self) -> Lending<Self>
+    where
+        Self: Sized,
+    {
+        Lending { iter: self }
+    }
+
+    /// Box this iterator up into a [`BoxedInfinite`](crate::BoxedInfinite),
+    /// erasing its type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{BoxedInfinite, InfiniteIterator, InfiniteIteratorExt};
+    ///
+    /// fn evens_or_odds(even: bool) -> BoxedInfinite<'static, u32> {
+    ///     if even {
+    ///         (0..).step_by(2).boxed()
+    ///     } else {
+    ///         (1..).step_by(2).boxed()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(evens_or_odds(false).next_infinite(), 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn boxed<'a>(self) -> crate::BoxedInfinite<'a, Self::Item>
+    where
+        Self: Sized + 'a,
+    {
+        alloc::boxed::Box::new(self)
+    }
+}
+
+impl<I: ?Sized + InfiniteIterator> InfiniteIteratorExt for I {}
+
+/// An extension trait providing methods to turn
+/// any [`Iterator`] into an [`InfiniteIterator`].
+///
+/// This trait is implemented for every [`Iterator`].
+pub trait IteratorExt: Iterator {
+    /// Yield the items of this iterator,
+    /// then repeat the given value forever once it ends.
+    ///
+    /// # Examples
+    ///
+    /// Padding a signal with zeros:
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, IteratorExt};
+    ///
+    /// let mut iter = [3, 1, 4].into_iter().or_repeat(0);
+    /// assert_eq!(iter.next_infinite(), 3);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// assert_eq!(iter.next_infinite(), 4);
+    /// assert_eq!(iter.next_infinite(), 0);
+    /// assert_eq!(iter.next_infinite(), 0);
+    /// ```
+    fn or_repeat(self, value: Self::Item) -> OrRepeat<Self, Self::Item>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        OrRepeat::new(self, value)
+    }
+
+    /// Yield the items of this iterator,
+    /// then call the given closure forever once it ends.
+    ///
+    /// Unlike [`or_repeat`](Self::or_repeat),
+    /// this does not require the items to be [`Clone`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, IteratorExt};
+    ///
+    /// let mut iter = [vec![1]].into_iter().or_repeat_with(Vec::new);
+    /// assert_eq!(iter.next_infinite(), [1]);
+    /// assert_eq!(iter.next_infinite(), []);
+    /// assert_eq!(iter.next_infinite(), []);
+    /// ```
+    fn or_repeat_with<F>(self, f: F) -> OrRepeatWith<Self, F>
+    where
+        Self: Sized,
+        F: FnMut() -> Self::Item,
+    {
+        OrRepeatWith::new(self, f)
+    }
+
+    /// Yield the items of this iterator,
+    /// then cycle through them forever once it ends.
+    ///
+    /// Unlike [`Iterator::cycle`],
+    /// this does not require the iterator to be [`Clone`]:
+    /// items are buffered as they are yielded.
+    /// Returns [`None`] if the iterator is empty,
+    /// in which case there would be nothing to cycle through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, IteratorExt};
+    ///
+    /// let mut iter = [1, 2].into_iter().or_cycle().unwrap();
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// assert_eq!(iter.next_infinite(), 2);
+    /// assert_eq!(iter.next_infinite(), 1);
+    /// assert_eq!(iter.next_infinite(), 2);
+    ///
+    /// assert!(core::iter::empty::<u32>().or_cycle().is_none());
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn or_cycle(self) -> Option<crate::OrCycle<Self>>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        crate::OrCycle::new(self)
+    }
+
+    /// Flatten an iterator of infinite iterators
+    /// when the outer iterator may be finite.
+    ///
+    /// [`Iterator::flatten`] is only infinite when the outer iterator is infinite,
+    /// but flattening a non-empty iterator of infinite iterators
+    /// is also infinite:
+    /// the first inner iterator never ends,
+    /// so the rest are never reached.
+    /// This method therefore returns that first inner iterator,
+    /// or [`None`] if the outer iterator is empty.
+    ///
+    /// Unlike [`Iterator::flatten`],
+    /// this advances the outer iterator immediately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::{InfiniteIterator, IteratorExt};
+    ///
+    /// let mut iter = [5.., 10..].into_iter().flatten_first_infinite().unwrap();
+    /// assert_eq!(iter.next_infinite(), 5);
+    /// assert_eq!(iter.next_infinite(), 6);
+    ///
+    /// assert!(core::iter::empty::<core::ops::RangeFrom<u32>>()
+    ///     .flatten_first_infinite()
+    ///     .is_none());
+    /// ```
+    fn flatten_first_infinite(
+        mut self,
+    ) -> Option<<Self::Item as IntoInfiniteIterator>::IntoInfiniteIter>
+    where
+        Self: Sized,
+        Self::Item: IntoInfiniteIterator,
+    {
+        self.next().map(IntoInfiniteIterator::into_infinite_iter)
+    }
+}
+
+impl<I: ?Sized + Iterator> IteratorExt for I {}
@@ -0,0 +1,63 @@
+use crate::InfiniteIterator;
+
+/// Conversion into an [`InfiniteIterator`].
+///
+/// This is the infinite counterpart of [`IntoIterator`].
+/// It is implemented for every [`InfiniteIterator`],
+/// and can additionally be implemented by container-like types
+/// that can always be turned into an infinite iterator,
+/// for example a non-empty ring buffer.
+/// Types implementing this trait can be used directly with [`ifor!`](crate::ifor).
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{ifor, IntoInfiniteIterator, InfiniteIterator};
+///
+/// struct Multiples {
+///     of: usize,
+/// }
+///
+/// impl IntoInfiniteIterator for Multiples {
+///     type Item = usize;
+///     type IntoInfiniteIter = std::iter::StepBy<std::ops::RangeFrom<usize>>;
+///
+///     fn into_infinite_iter(self) -> Self::IntoInfiniteIter {
+///         (0..).step_by(self.of)
+///     }
+/// }
+///
+/// fn sum_of_first<I: IntoInfiniteIterator<Item = usize>>(iter: I, n: usize) -> usize {
+///     let mut iter = iter.into_infinite_iter();
+///     (0..n).map(|_| iter.next_infinite()).sum()
+/// }
+///
+/// assert_eq!(sum_of_first(Multiples { of: 3 }, 4), 18);
+///
+/// let multiples = Multiples { of: 4 };
+/// let item = ifor!(num in multiples {
+///     if num > 10 {
+///         break num;
+///     }
+/// });
+/// assert_eq!(item, 12);
+/// ```
+pub trait IntoInfiniteIterator {
+    /// The type of the elements being iterated over.
+    type Item;
+
+    /// Which kind of infinite iterator are we turning this into?
+    type IntoInfiniteIter: InfiniteIterator<Item = Self::Item>;
+
+    /// Creates an infinite iterator from a value.
+    fn into_infinite_iter(self) -> Self::IntoInfiniteIter;
+}
+
+impl<I: InfiniteIterator> IntoInfiniteIterator for I {
+    type Item = I::Item;
+    type IntoInfiniteIter = I;
+
+    fn into_infinite_iter(self) -> Self::IntoInfiniteIter {
+        self
+    }
+}
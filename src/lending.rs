@@ -0,0 +1,101 @@
+use crate::InfiniteIterator;
+
+/// An infinite iterator whose items may borrow from the iterator itself.
+///
+/// This is the lending counterpart of [`InfiniteIterator`]:
+/// because each item may borrow from the iterator,
+/// only one item can be alive at a time.
+/// Any [`InfiniteIterator`] can be turned into an `InfiniteLendingIterator`
+/// using [`InfiniteIteratorExt::lending`](crate::InfiniteIteratorExt::lending).
+///
+/// # Examples
+///
+/// A scratch buffer refilled forever:
+///
+/// ```
+/// use infinite_iterator::InfiniteLendingIterator;
+///
+/// struct Chunks {
+///     buf: [u8; 4],
+///     next: u8,
+/// }
+///
+/// impl InfiniteLendingIterator for Chunks {
+///     type Item<'a> = &'a [u8];
+///
+///     fn next_infinite(&mut self) -> Self::Item<'_> {
+///         for byte in &mut self.buf {
+///             *byte = self.next;
+///             self.next = self.next.wrapping_add(1);
+///         }
+///         &self.buf
+///     }
+/// }
+///
+/// let mut chunks = Chunks { buf: [0; 4], next: 0 };
+/// assert_eq!(chunks.next_infinite(), [0, 1, 2, 3]);
+/// assert_eq!(chunks.next_infinite(), [4, 5, 6, 7]);
+/// ```
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub trait InfiniteLendingIterator {
+    /// The type of the elements being iterated over.
+    type Item<'a>
+    where
+        Self: 'a;
+
+    /// Get the next item of the iterator.
+    ///
+    /// This never returns [`None`] because the iterator never ends.
+    fn next_infinite(&mut self) -> Self::Item<'_>;
+
+    /// Like [`InfiniteIterator::for_each_infinite`],
+    /// but for lending iterators.
+    fn for_each_infinite<F>(mut self, mut f: F) -> !
+    where
+        Self: Sized,
+        F: FnMut(Self::Item<'_>),
+    {
+        loop {
+            f(self.next_infinite());
+        }
+    }
+}
+
+impl<I: ?Sized + InfiniteLendingIterator> InfiniteLendingIterator for &mut I {
+    type Item<'a>
+        = I::Item<'a>
+    where
+        Self: 'a;
+
+    fn next_infinite(&mut self) -> Self::Item<'_> {
+        (**self).next_infinite()
+    }
+}
+
+/// An [`InfiniteLendingIterator`] that yields the items of an [`InfiniteIterator`].
+///
+/// This `struct` is created by
+/// [`InfiniteIteratorExt::lending`](crate::InfiniteIteratorExt::lending).
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Lending<I> {
+    pub(crate) iter: I,
+}
+
+impl<I> Lending<I> {
+    /// Extract the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.iter
+    }
+}
+
+impl<I: InfiniteIterator> InfiniteLendingIterator for Lending<I> {
+    type Item<'a>
+        = I::Item
+    where
+        Self: 'a;
+
+    fn next_infinite(&mut self) -> Self::Item<'_> {
+        self.iter.next_infinite()
+    }
+}
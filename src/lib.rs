@@ -10,6 +10,17 @@
 //!
 //! [`next`]: https://doc.rust-lang.org/stable/std/iter/trait.Iterator.html#tymethod.next
 #![no_std]
+#![cfg_attr(
+    feature = "nightly",
+    feature(
+        extend_one,
+        iter_advance_by,
+        iter_array_chunks,
+        iter_intersperse,
+        iter_map_windows,
+        trusted_len
+    )
+)]
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -18,6 +29,38 @@ extern crate std;
 extern crate alloc;
 
 use core::iter;
+use core::marker::PhantomData;
+
+mod ext;
+pub use ext::{InfiniteIteratorExt, IteratorExt};
+
+mod adapters;
+pub use adapters::*;
+
+pub mod overflow;
+
+pub mod sources;
+
+mod chunk_writer;
+pub use chunk_writer::ChunkWriter;
+
+mod tuple;
+pub use tuple::HomogeneousTuple;
+
+mod lending;
+pub use lending::{InfiniteLendingIterator, Lending};
+
+mod try_iter;
+pub use try_iter::{FilterOk, InfiniteTryIterator, MapOk};
+
+mod into_infinite;
+pub use into_infinite::IntoInfiniteIterator;
+
+mod assert_infinite;
+pub use assert_infinite::{assert_infinite, AssertInfinite};
+
+mod assume_infinite;
+pub use assume_infinite::{assume_infinite, AssumeInfinite};
 
 /// An [`Iterator`] that never ends.
 ///
@@ -27,8 +70,8 @@ use core::iter;
 /// the following invariants must be upheld:
 /// 1. `Some(iter.next_infinite())` must always give the same result as `iter.next()`.
 /// 2. No default-implemented iterator methods may be overriden
-///     to have visibly different behaviour
-///     than their default implementations.
+///    to have visibly different behaviour
+///    than their default implementations.
 /// 3. `size_hint().1` must always be `None`.
 /// 4. The type must not implement [`ExactSizeIterator`].
 ///
@@ -141,12 +184,174 @@ pub trait InfiniteIterator: Iterator {
             i += 1;
         }
     }
+
+    /// Write successive items of this iterator into every slot of `chunk`.
+    ///
+    /// This is called by
+    /// [`InfiniteIteratorExt::next_array`]
+    /// and the other bulk methods built on it.
+    /// Unlike them it is not generic,
+    /// so it can be overridden to produce a whole chunk
+    /// in a single dynamic call when used through a trait object,
+    /// instead of one call to [`next_infinite`](Self::next_infinite) per item.
+    /// Implementations must fill the whole chunk;
+    /// callers panic otherwise.
+    ///
+    /// See [`ChunkWriter`] for an example.
+    fn next_chunk_into(&mut self, chunk: &mut ChunkWriter<'_, Self::Item>) {
+        while !chunk.is_full() {
+            chunk.push(self.next_infinite());
+        }
+    }
+
+    /// Proof that this iterator never ends, if it is known.
+    ///
+    /// This is an implementation detail of this crate's adapters
+    /// and is not part of the public API.
+    #[doc(hidden)]
+    fn __trusted_token() -> Option<TrustedToken<Self>>
+    where
+        Self: Sized,
+    {
+        None
+    }
+}
+
+/// Proof that iterators of type `T` never end,
+/// from either end if they are double-ended.
+///
+/// Adapters check for this
+/// to avoid a panicking branch in [`InfiniteIterator::next_infinite`]
+/// when their underlying iterator is [`TrustedInfinite`];
+/// it is not part of the public API.
+#[doc(hidden)]
+pub struct TrustedToken<T: ?Sized>(PhantomData<fn() -> T>);
+
+impl<T: ?Sized + TrustedInfinite> TrustedToken<T> {
+    /// Create a token for a type implementing [`TrustedInfinite`].
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: ?Sized + TrustedInfinite> Default for TrustedToken<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized> TrustedToken<T> {
+    /// # Safety
+    ///
+    /// Iterators of type `T` must never end,
+    /// from either end if they are double-ended.
+    unsafe fn assume() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Like `iter.next().unwrap()`,
+/// but without the panicking branch when the iterator has a [`TrustedToken`].
+fn next_adapter<I: InfiniteIterator>(iter: &mut I) -> I::Item {
+    match I::__trusted_token() {
+        // SAFETY: The token proves that the iterator never ends.
+        Some(_) => unsafe { iter.next().unwrap_unchecked() },
+        None => iter.next().unwrap(),
+    }
+}
+
+/// Like `iter.next_back().unwrap()`,
+/// but without the panicking branch when the iterator has a [`TrustedToken`].
+fn next_back_adapter<I: InfiniteDoubleEndedIterator>(iter: &mut I) -> I::Item {
+    match I::__trusted_token() {
+        // SAFETY: The token proves that the iterator never ends from the back either.
+        Some(_) => unsafe { iter.next_back().unwrap_unchecked() },
+        None => iter.next_back().unwrap(),
+    }
+}
+
+/// An [`InfiniteIterator`] that is guaranteed to never end,
+/// such that `unsafe` code may rely on it.
+///
+/// This crate's implementations of [`InfiniteIterator`] for adapters
+/// call [`Iterator::next`] and unwrap the result,
+/// which leaves a panicking branch in the generated code
+/// unless the compiler can see that the adapter never ends.
+/// When the underlying iterator is one of this crate's `TrustedInfinite` types,
+/// the adapters skip that branch automatically;
+/// otherwise [`next_trusted`](Self::next_trusted) can be used
+/// to get the next item with no such branch.
+///
+/// The adapters cannot see through `&mut I` or `Box<I>`,
+/// because `I` may be a trait object whose type is not known statically,
+/// so adapters over them, such as `(&mut iter).map(f)`, keep the branch.
+/// Call [`next_trusted`](Self::next_trusted) on such an adapter
+/// to avoid it.
+///
+/// # Safety
+///
+/// Implementors must guarantee that the iterator never ends:
+/// [`Iterator::next`] and [`Iterator::nth`] must never return [`None`],
+/// and [`Iterator::try_fold`] and [`Iterator::fold`] must loop forever
+/// unless the closure short-circuits.
+/// If the iterator also implements [`DoubleEndedIterator`],
+/// the same must hold from the back:
+/// [`DoubleEndedIterator::next_back`] and [`DoubleEndedIterator::nth_back`]
+/// must never return [`None`],
+/// and [`DoubleEndedIterator::try_rfold`] and [`DoubleEndedIterator::rfold`]
+/// must loop forever unless the closure short-circuits.
+pub unsafe trait TrustedInfinite: InfiniteIterator {
+    /// Like [`InfiniteIterator::next_infinite`],
+    /// but does not contain a panicking branch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::TrustedInfinite;
+    ///
+    /// let mut iter = core::iter::repeat(5).map(|num| num * 2);
+    /// assert_eq!(iter.next_trusted(), 10);
+    /// ```
+    fn next_trusted(&mut self) -> Self::Item {
+        // SAFETY: Implementors of `TrustedInfinite` guarantee that `next` never returns `None`.
+        unsafe { self.next().unwrap_unchecked() }
+    }
+}
+
+/// An [`InfiniteIterator`] that is also infinite
+/// when iterated from the back.
+///
+/// # Invariants
+///
+/// In addition to the invariants of [`InfiniteIterator`],
+/// `Some(iter.next_back_infinite())` must always give the same result
+/// as `iter.next_back()`.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{InfiniteDoubleEndedIterator, InfiniteIterator};
+///
+/// let mut iter = core::iter::repeat(5).map(|num| num * 2);
+/// assert_eq!(iter.next_back_infinite(), 10);
+/// assert_eq!(iter.rev().next_infinite(), 10);
+/// ```
+pub trait InfiniteDoubleEndedIterator: InfiniteIterator + DoubleEndedIterator {
+    /// Like [`DoubleEndedIterator::next_back`],
+    /// but never returning [`None`] because the iterator never ends.
+    fn next_back_infinite(&mut self) -> Self::Item;
 }
 
+// These do not forward `__trusted_token`, which needs `I: Sized`;
+// see the documentation of `TrustedInfinite`.
 impl<I: ?Sized + InfiniteIterator> InfiniteIterator for &mut I {
     fn next_infinite(&mut self) -> Self::Item {
         (**self).next_infinite()
     }
+
+    fn next_chunk_into(&mut self, chunk: &mut ChunkWriter<'_, Self::Item>) {
+        (**self).next_chunk_into(chunk);
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -154,15 +359,31 @@ impl<I: ?Sized + InfiniteIterator> InfiniteIterator for alloc::boxed::Box<I> {
     fn next_infinite(&mut self) -> Self::Item {
         (**self).next_infinite()
     }
+
+    fn next_chunk_into(&mut self, chunk: &mut ChunkWriter<'_, Self::Item>) {
+        (**self).next_chunk_into(chunk);
+    }
 }
 
+/// A boxed, type-erased [`InfiniteIterator`].
+///
+/// This can be created with
+/// [`InfiniteIteratorExt::boxed`](crate::InfiniteIteratorExt::boxed).
+#[cfg(feature = "alloc")]
+pub type BoxedInfinite<'a, T> = alloc::boxed::Box<dyn InfiniteIterator<Item = T> + 'a>;
+
 impl<'item, I, T> InfiniteIterator for iter::Cloned<I>
 where
     T: 'item + Clone,
     I: InfiniteIterator<Item = &'item T>,
 {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Cloned` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
@@ -172,7 +393,12 @@ where
     I: InfiniteIterator<Item = &'item T>,
 {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Copied` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
@@ -181,12 +407,21 @@ impl<A: Clone> InfiniteIterator for iter::Repeat<A> {
         // SAFETY: `Repeat` never ends.
         unsafe { self.next().unwrap_unchecked() }
     }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        Some(TrustedToken::new())
+    }
 }
 
 impl<F: FnMut() -> A, A> InfiniteIterator for iter::RepeatWith<F> {
     fn next_infinite(&mut self) -> Self::Item {
+        // SAFETY: `RepeatWith` never ends.
         unsafe { self.next().unwrap_unchecked() }
     }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        Some(TrustedToken::new())
+    }
 }
 
 impl<A, B> InfiniteIterator for iter::Chain<A, B>
@@ -195,7 +430,12 @@ where
     B: InfiniteIterator<Item = A::Item>,
 {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Chain` only ends when its second iterator ends.
+        B::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
@@ -205,7 +445,14 @@ where
     B: InfiniteIterator,
 {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Zip` only ends when one of its underlying iterators ends.
+        A::__trusted_token()
+            .and(B::__trusted_token())
+            .map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
@@ -215,7 +462,12 @@ where
     P: FnMut(&I::Item) -> bool,
 {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Filter` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
@@ -225,42 +477,72 @@ where
     F: FnMut(I::Item) -> Option<B>,
 {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `FilterMap` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
 impl<B, I, F> InfiniteIterator for iter::Map<I, F>
 where
     I: InfiniteIterator,
-    F: FnMut(I::Item) -> Option<B>,
+    F: FnMut(I::Item) -> B,
 {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Map` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
 // Require `InfiniteIterator` to prevent empty iterators
 impl<I: Clone + InfiniteIterator> InfiniteIterator for iter::Cycle<I> {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Cycle` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
 impl<I: InfiniteIterator> InfiniteIterator for iter::Enumerate<I> {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Enumerate` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
 impl<I: InfiniteIterator> InfiniteIterator for iter::Fuse<I> {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Fuse` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
 impl<I: InfiniteIterator> InfiniteIterator for iter::Peekable<I> {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Peekable` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
@@ -298,7 +580,12 @@ impl<I: InfiniteIterator> PeekableExt for iter::Peekable<I> {
 
 impl<I: InfiniteIterator> InfiniteIterator for iter::Skip<I> {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Skip` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
@@ -308,13 +595,23 @@ where
     P: FnMut(&I::Item) -> bool,
 {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `SkipWhile` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
 impl<I: InfiniteIterator> InfiniteIterator for iter::StepBy<I> {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `StepBy` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
@@ -324,7 +621,12 @@ where
     F: FnMut(&I::Item),
 {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Inspect` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
@@ -334,7 +636,12 @@ where
     I::Item: IntoIterator,
 {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Flatten` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
@@ -345,7 +652,72 @@ where
     F: FnMut(I::Item) -> U,
 {
     fn next_infinite(&mut self) -> Self::Item {
-        self.next().unwrap()
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `FlatMap` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<I> InfiniteIterator for iter::Intersperse<I>
+where
+    I: InfiniteIterator,
+    I::Item: Clone,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Intersperse` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<I, G> InfiniteIterator for iter::IntersperseWith<I, G>
+where
+    I: InfiniteIterator,
+    G: FnMut() -> I::Item,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `IntersperseWith` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<I, F, R, const N: usize> InfiniteIterator for iter::MapWindows<I, F, N>
+where
+    I: InfiniteIterator,
+    F: FnMut(&[I::Item; N]) -> R,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `MapWindows` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
+    }
+}
+
+#[cfg(feature = "nightly")]
+impl<I: InfiniteIterator, const N: usize> InfiniteIterator for iter::ArrayChunks<I, N> {
+    fn next_infinite(&mut self) -> Self::Item {
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `ArrayChunks` only ends when its underlying iterator ends.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
     }
 }
 
@@ -357,6 +729,10 @@ where
         // SAFETY: The `RangeFrom` iterator never ends
         unsafe { self.next().unwrap_unchecked() }
     }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        Some(TrustedToken::new())
+    }
 }
 
 #[cfg(feature = "std")]
@@ -365,6 +741,10 @@ impl InfiniteIterator for std::net::Incoming<'_> {
         // SAFETY: The Incoming iterator never ends.
         unsafe { self.next().unwrap_unchecked() }
     }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        Some(TrustedToken::new())
+    }
 }
 
 #[cfg(all(feature = "std", unix))]
@@ -373,8 +753,263 @@ impl InfiniteIterator for std::os::unix::net::Incoming<'_> {
         // SAFETY: The Incoming iterator never ends
         unsafe { self.next().unwrap_unchecked() }
     }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        Some(TrustedToken::new())
+    }
+}
+
+impl<I: ?Sized + InfiniteDoubleEndedIterator> InfiniteDoubleEndedIterator for &mut I {
+    fn next_back_infinite(&mut self) -> Self::Item {
+        (**self).next_back_infinite()
+    }
 }
 
+#[cfg(feature = "alloc")]
+impl<I: ?Sized + InfiniteDoubleEndedIterator> InfiniteDoubleEndedIterator for alloc::boxed::Box<I> {
+    fn next_back_infinite(&mut self) -> Self::Item {
+        (**self).next_back_infinite()
+    }
+}
+
+impl<A: Clone> InfiniteDoubleEndedIterator for iter::Repeat<A> {
+    fn next_back_infinite(&mut self) -> Self::Item {
+        // SAFETY: `Repeat` never ends.
+        unsafe { self.next_back().unwrap_unchecked() }
+    }
+}
+
+impl<I: InfiniteDoubleEndedIterator> InfiniteIterator for iter::Rev<I> {
+    fn next_infinite(&mut self) -> Self::Item {
+        next_adapter(self)
+    }
+
+    fn __trusted_token() -> Option<TrustedToken<Self>> {
+        // SAFETY: `Rev` swaps the ends of its underlying iterator,
+        // which the token proves never ends from either end.
+        I::__trusted_token().map(|_| unsafe { TrustedToken::assume() })
+    }
+}
+
+impl<I: InfiniteDoubleEndedIterator> InfiniteDoubleEndedIterator for iter::Rev<I> {
+    fn next_back_infinite(&mut self) -> Self::Item {
+        next_back_adapter(self)
+    }
+}
+
+impl<'item, I, T> InfiniteDoubleEndedIterator for iter::Cloned<I>
+where
+    T: 'item + Clone,
+    I: InfiniteDoubleEndedIterator<Item = &'item T>,
+{
+    fn next_back_infinite(&mut self) -> Self::Item {
+        next_back_adapter(self)
+    }
+}
+
+impl<'item, I, T> InfiniteDoubleEndedIterator for iter::Copied<I>
+where
+    T: 'item + Copy,
+    I: InfiniteDoubleEndedIterator<Item = &'item T>,
+{
+    fn next_back_infinite(&mut self) -> Self::Item {
+        next_back_adapter(self)
+    }
+}
+
+impl<A, B> InfiniteDoubleEndedIterator for iter::Chain<A, B>
+where
+    A: DoubleEndedIterator,
+    B: InfiniteDoubleEndedIterator<Item = A::Item>,
+{
+    fn next_back_infinite(&mut self) -> Self::Item {
+        next_back_adapter(self)
+    }
+}
+
+impl<I, P> InfiniteDoubleEndedIterator for iter::Filter<I, P>
+where
+    I: InfiniteDoubleEndedIterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    fn next_back_infinite(&mut self) -> Self::Item {
+        next_back_adapter(self)
+    }
+}
+
+impl<B, I, F> InfiniteDoubleEndedIterator for iter::FilterMap<I, F>
+where
+    I: InfiniteDoubleEndedIterator,
+    F: FnMut(I::Item) -> Option<B>,
+{
+    fn next_back_infinite(&mut self) -> Self::Item {
+        next_back_adapter(self)
+    }
+}
+
+impl<B, I, F> InfiniteDoubleEndedIterator for iter::Map<I, F>
+where
+    I: InfiniteDoubleEndedIterator,
+    F: FnMut(I::Item) -> B,
+{
+    fn next_back_infinite(&mut self) -> Self::Item {
+        next_back_adapter(self)
+    }
+}
+
+impl<I: InfiniteDoubleEndedIterator> InfiniteDoubleEndedIterator for iter::Fuse<I> {
+    fn next_back_infinite(&mut self) -> Self::Item {
+        next_back_adapter(self)
+    }
+}
+
+impl<I: InfiniteDoubleEndedIterator> InfiniteDoubleEndedIterator for iter::Peekable<I> {
+    fn next_back_infinite(&mut self) -> Self::Item {
+        next_back_adapter(self)
+    }
+}
+
+impl<I, F> InfiniteDoubleEndedIterator for iter::Inspect<I, F>
+where
+    I: InfiniteDoubleEndedIterator,
+    F: FnMut(&I::Item),
+{
+    fn next_back_infinite(&mut self) -> Self::Item {
+        next_back_adapter(self)
+    }
+}
+
+impl<I> InfiniteDoubleEndedIterator for iter::Flatten<I>
+where
+    I: InfiniteDoubleEndedIterator,
+    I::Item: IntoIterator,
+    <I::Item as IntoIterator>::IntoIter: DoubleEndedIterator,
+{
+    fn next_back_infinite(&mut self) -> Self::Item {
+        next_back_adapter(self)
+    }
+}
+
+impl<I, U, F> InfiniteDoubleEndedIterator for iter::FlatMap<I, U, F>
+where
+    I: InfiniteDoubleEndedIterator,
+    U: IntoIterator,
+    U::IntoIter: DoubleEndedIterator,
+    F: FnMut(I::Item) -> U,
+{
+    fn next_back_infinite(&mut self) -> Self::Item {
+        next_back_adapter(self)
+    }
+}
+
+// SAFETY: These iterators never end.
+unsafe impl<A: Clone> TrustedInfinite for iter::Repeat<A> {}
+unsafe impl<F: FnMut() -> A, A> TrustedInfinite for iter::RepeatWith<F> {}
+unsafe impl<A> TrustedInfinite for core::ops::RangeFrom<A> where core::ops::RangeFrom<A>: Iterator {}
+#[cfg(feature = "std")]
+unsafe impl TrustedInfinite for std::net::Incoming<'_> {}
+#[cfg(all(feature = "std", unix))]
+unsafe impl TrustedInfinite for std::os::unix::net::Incoming<'_> {}
+
+// SAFETY: These iterators forward to their underlying iterator.
+unsafe impl<I: ?Sized + TrustedInfinite> TrustedInfinite for &mut I {}
+#[cfg(feature = "alloc")]
+unsafe impl<I: ?Sized + TrustedInfinite> TrustedInfinite for alloc::boxed::Box<I> {}
+
+// SAFETY: These adapters only end when their underlying iterator ends.
+unsafe impl<I: TrustedInfinite + InfiniteDoubleEndedIterator> TrustedInfinite for iter::Rev<I> {}
+unsafe impl<'item, I, T> TrustedInfinite for iter::Cloned<I>
+where
+    T: 'item + Clone,
+    I: TrustedInfinite<Item = &'item T>,
+{
+}
+unsafe impl<'item, I, T> TrustedInfinite for iter::Copied<I>
+where
+    T: 'item + Copy,
+    I: TrustedInfinite<Item = &'item T>,
+{
+}
+unsafe impl<A, B> TrustedInfinite for iter::Chain<A, B>
+where
+    A: Iterator,
+    B: TrustedInfinite<Item = A::Item>,
+{
+}
+unsafe impl<A: TrustedInfinite, B: TrustedInfinite> TrustedInfinite for iter::Zip<A, B> {}
+unsafe impl<I, P> TrustedInfinite for iter::Filter<I, P>
+where
+    I: TrustedInfinite,
+    P: FnMut(&I::Item) -> bool,
+{
+}
+unsafe impl<B, I, F> TrustedInfinite for iter::FilterMap<I, F>
+where
+    I: TrustedInfinite,
+    F: FnMut(I::Item) -> Option<B>,
+{
+}
+unsafe impl<B, I, F> TrustedInfinite for iter::Map<I, F>
+where
+    I: TrustedInfinite,
+    F: FnMut(I::Item) -> B,
+{
+}
+unsafe impl<I: Clone + TrustedInfinite> TrustedInfinite for iter::Cycle<I> {}
+unsafe impl<I: TrustedInfinite> TrustedInfinite for iter::Enumerate<I> {}
+unsafe impl<I: TrustedInfinite> TrustedInfinite for iter::Fuse<I> {}
+unsafe impl<I: TrustedInfinite> TrustedInfinite for iter::Peekable<I> {}
+unsafe impl<I: TrustedInfinite> TrustedInfinite for iter::Skip<I> {}
+unsafe impl<I, P> TrustedInfinite for iter::SkipWhile<I, P>
+where
+    I: TrustedInfinite,
+    P: FnMut(&I::Item) -> bool,
+{
+}
+unsafe impl<I: TrustedInfinite> TrustedInfinite for iter::StepBy<I> {}
+unsafe impl<I, F> TrustedInfinite for iter::Inspect<I, F>
+where
+    I: TrustedInfinite,
+    F: FnMut(&I::Item),
+{
+}
+unsafe impl<I> TrustedInfinite for iter::Flatten<I>
+where
+    I: TrustedInfinite,
+    I::Item: IntoIterator,
+{
+}
+unsafe impl<I, U, F> TrustedInfinite for iter::FlatMap<I, U, F>
+where
+    I: TrustedInfinite,
+    U: IntoIterator,
+    F: FnMut(I::Item) -> U,
+{
+}
+#[cfg(feature = "nightly")]
+unsafe impl<I> TrustedInfinite for iter::Intersperse<I>
+where
+    I: TrustedInfinite,
+    I::Item: Clone,
+{
+}
+#[cfg(feature = "nightly")]
+unsafe impl<I, G> TrustedInfinite for iter::IntersperseWith<I, G>
+where
+    I: TrustedInfinite,
+    G: FnMut() -> I::Item,
+{
+}
+#[cfg(feature = "nightly")]
+unsafe impl<I, F, R, const N: usize> TrustedInfinite for iter::MapWindows<I, F, N>
+where
+    I: TrustedInfinite,
+    F: FnMut(&[I::Item; N]) -> R,
+{
+}
+#[cfg(feature = "nightly")]
+unsafe impl<I: TrustedInfinite, const N: usize> TrustedInfinite for iter::ArrayChunks<I, N> {}
+
 /// An extension of `for in` loops with better support for infinite iterators.
 ///
 /// This macro presents a _superset_ of regular `for` loops:
@@ -449,9 +1084,12 @@ macro_rules! ifor {
 #[macro_export]
 macro_rules! __ifor_inner {
     ($($label:lifetime:)? for $pat:pat in ($expr:expr) $block:block) => {
-        match $crate::__private::IntoIterator::into_iter($expr) {
+        match $crate::__private::IntoMaybeInfinite($expr) {
             iter => {
-                let mut iter = $crate::__private::MaybeInfinite(iter);
+                let mut iter = {
+                    use $crate::__private::IntoMaybeInfiniteFallback;
+                    iter.into_maybe_infinite()
+                };
                 $($label:)? loop {
                     let $pat = {
                         use $crate::__private::TryNextFallback;
@@ -475,16 +1113,85 @@ macro_rules! __ifor_inner {
     };
 }
 
+/// Zip several infinite iterators together into an iterator of flat tuples.
+///
+/// `izip!(a, b, c)` is like `a.zip_infinite(b).zip_infinite(c)`,
+/// except that it yields `(a, b, c)` instead of `((a, b), c)`.
+/// Each argument may be anything implementing [`IntoInfiniteIterator`],
+/// and the result implements [`InfiniteIterator`].
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{izip, InfiniteIterator};
+///
+/// let mut iter = izip!(0.., (0..).step_by(2), core::iter::repeat('x'));
+/// assert_eq!(iter.next_infinite(), (0, 0, 'x'));
+/// assert_eq!(iter.next_infinite(), (1, 2, 'x'));
+/// ```
+#[macro_export]
+macro_rules! izip {
+    // Build a closure flattening nested pairs of the form `((a, b), c)`.
+    (@closure $p:pat => $tup:expr) => {
+        |$p| $tup
+    };
+    (@closure $p:pat => ($($tup:tt)*), $_iter:expr $(, $tail:expr)*) => {
+        $crate::izip!(@closure ($p, b) => ($($tup)*, b) $(, $tail)*)
+    };
+
+    ($first:expr $(,)?) => {
+        $crate::IntoInfiniteIterator::into_infinite_iter($first)
+    };
+    ($first:expr, $second:expr $(,)?) => {
+        $crate::InfiniteIteratorExt::zip_infinite(
+            $crate::IntoInfiniteIterator::into_infinite_iter($first),
+            $crate::IntoInfiniteIterator::into_infinite_iter($second),
+        )
+    };
+    ($first:expr $(, $rest:expr)* $(,)?) => {{
+        let iter = $crate::IntoInfiniteIterator::into_infinite_iter($first);
+        $(
+            let iter = $crate::InfiniteIteratorExt::zip_infinite(
+                iter,
+                $crate::IntoInfiniteIterator::into_infinite_iter($rest),
+            );
+        )*
+        $crate::InfiniteIteratorExt::map_infinite(
+            iter,
+            $crate::izip!(@closure a => (a) $(, $rest)*),
+        )
+    }};
+}
+
 // Not public API.
 #[doc(hidden)]
 pub mod __private {
     use crate::InfiniteIterator;
+    use crate::IntoInfiniteIterator;
 
     pub use core::compile_error;
     pub use Err;
-    pub use IntoIterator;
     pub use Ok;
 
+    pub struct IntoMaybeInfinite<T>(pub T);
+
+    impl<T: IntoInfiniteIterator> IntoMaybeInfinite<T> {
+        pub fn into_maybe_infinite(self) -> MaybeInfinite<T::IntoInfiniteIter> {
+            MaybeInfinite(self.0.into_infinite_iter())
+        }
+    }
+
+    pub trait IntoMaybeInfiniteFallback: Sized {
+        type IntoIter;
+        fn into_maybe_infinite(self) -> MaybeInfinite<Self::IntoIter>;
+    }
+    impl<T: IntoIterator> IntoMaybeInfiniteFallback for IntoMaybeInfinite<T> {
+        type IntoIter = T::IntoIter;
+        fn into_maybe_infinite(self) -> MaybeInfinite<Self::IntoIter> {
+            MaybeInfinite(self.0.into_iter())
+        }
+    }
+
     pub struct MaybeInfinite<I>(pub I);
 
     impl<I: InfiniteIterator> MaybeInfinite<I> {
@@ -516,3 +1223,49 @@ pub mod __private {
         pub fn into_break(self) {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{assert_infinite, assume_infinite, InfiniteIterator};
+    use core::iter;
+
+    fn is_trusted<I: InfiniteIterator>(_: &I) -> bool {
+        I::__trusted_token().is_some()
+    }
+
+    #[test]
+    fn trusted_sources() {
+        assert!(is_trusted(&(0_u32..)));
+        assert!(is_trusted(&iter::repeat(1)));
+        assert!(is_trusted(&iter::repeat_with(|| 1)));
+        // SAFETY: `Cycle` over a non-empty slice never ends.
+        assert!(is_trusted(&unsafe {
+            assume_infinite([1, 2].iter().cycle())
+        }));
+    }
+
+    #[test]
+    fn trusted_adapters() {
+        let iter = (0_u32..)
+            .map(|x| x * 2)
+            .enumerate()
+            .zip(iter::repeat(()))
+            .filter(|_| true)
+            .skip(1)
+            .step_by(2)
+            .peekable()
+            .fuse();
+        assert!(is_trusted(&iter));
+        assert!(is_trusted(&[1].iter().chain(iter::repeat(&1)).copied()));
+        assert!(is_trusted(&iter::repeat(&1).copied().map(|x| x + 1).rev()));
+    }
+
+    #[test]
+    fn untrusted() {
+        assert!(!is_trusted(&assert_infinite([1, 2])));
+        assert!(!is_trusted(&assert_infinite([1, 2]).map(|x| x + 1)));
+        assert!(!is_trusted(&(0_u32..).zip(assert_infinite([1, 2]))));
+        // See the documentation of `TrustedInfinite`.
+        assert!(!is_trusted(&(&mut (0_u32..)).map(|x| x + 1)));
+    }
+}
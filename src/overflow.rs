@@ -0,0 +1,229 @@
+//! Policies for handling integer overflow.
+//!
+//! Iterators that do arithmetic on their items
+//! take one of [`Wrapping`], [`Saturating`] or [`Checked`]
+//! to decide what happens when a result does not fit in the integer type,
+//! instead of panicking in debug builds like [`RangeFrom`] does.
+//!
+//! [`RangeFrom`]: core::ops::RangeFrom
+
+/// A primitive integer type.
+///
+/// This trait is implemented for every primitive integer type.
+/// It is sealed; it cannot be implemented manually.
+pub trait Integer: Copy + Ord + integer::Sealed {
+    #[doc(hidden)]
+    const ZERO: Self;
+
+    #[doc(hidden)]
+    const ONE: Self;
+
+    #[doc(hidden)]
+    fn is_even(self) -> bool;
+
+    #[doc(hidden)]
+    fn half(self) -> Self;
+
+    #[doc(hidden)]
+    fn wrapping_add(self, rhs: Self) -> Self;
+
+    #[doc(hidden)]
+    fn saturating_add(self, rhs: Self) -> Self;
+
+    #[doc(hidden)]
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn wrapping_sub(self, rhs: Self) -> Self;
+
+    #[doc(hidden)]
+    fn saturating_sub(self, rhs: Self) -> Self;
+
+    #[doc(hidden)]
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    #[doc(hidden)]
+    fn wrapping_mul(self, rhs: Self) -> Self;
+
+    #[doc(hidden)]
+    fn saturating_mul(self, rhs: Self) -> Self;
+
+    #[doc(hidden)]
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+mod integer {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_integer {
+    ($($t:ty)*) => {$(
+        impl integer::Sealed for $t {}
+
+        impl Integer for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn is_even(self) -> bool {
+                self % 2 == 0
+            }
+
+            fn half(self) -> Self {
+                self / 2
+            }
+
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$t>::wrapping_add(self, rhs)
+            }
+
+            fn saturating_add(self, rhs: Self) -> Self {
+                <$t>::saturating_add(self, rhs)
+            }
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$t>::wrapping_sub(self, rhs)
+            }
+
+            fn saturating_sub(self, rhs: Self) -> Self {
+                <$t>::saturating_sub(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$t>::wrapping_mul(self, rhs)
+            }
+
+            fn saturating_mul(self, rhs: Self) -> Self {
+                <$t>::saturating_mul(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        }
+    )*};
+}
+
+impl_integer!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// A way of handling integer overflow.
+///
+/// This trait is implemented by [`Wrapping`], [`Saturating`] and [`Checked`].
+/// It is sealed; it cannot be implemented manually.
+pub trait Policy: Copy + policy::Sealed {
+    /// The type of items yielded under this policy
+    /// by an iterator doing arithmetic on integers of type `T`.
+    type Output<T>;
+
+    #[doc(hidden)]
+    fn add<T: Integer>(self, lhs: T, rhs: T) -> Option<T>;
+
+    #[doc(hidden)]
+    fn sub<T: Integer>(self, lhs: T, rhs: T) -> Option<T>;
+
+    #[doc(hidden)]
+    fn mul<T: Integer>(self, lhs: T, rhs: T) -> Option<T>;
+
+    #[doc(hidden)]
+    fn output<T>(self, value: Option<T>) -> Self::Output<T>;
+}
+
+mod policy {
+    pub trait Sealed {}
+}
+
+/// Wrap around at the boundary of the integer type.
+///
+/// Items are yielded as plain integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Wrapping;
+
+impl policy::Sealed for Wrapping {}
+impl Policy for Wrapping {
+    type Output<T> = T;
+
+    fn add<T: Integer>(self, lhs: T, rhs: T) -> Option<T> {
+        Some(lhs.wrapping_add(rhs))
+    }
+
+    fn sub<T: Integer>(self, lhs: T, rhs: T) -> Option<T> {
+        Some(lhs.wrapping_sub(rhs))
+    }
+
+    fn mul<T: Integer>(self, lhs: T, rhs: T) -> Option<T> {
+        Some(lhs.wrapping_mul(rhs))
+    }
+
+    fn output<T>(self, value: Option<T>) -> Self::Output<T> {
+        match value {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+}
+
+/// Clamp at the minimum and maximum values of the integer type.
+///
+/// Items are yielded as plain integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Saturating;
+
+impl policy::Sealed for Saturating {}
+impl Policy for Saturating {
+    type Output<T> = T;
+
+    fn add<T: Integer>(self, lhs: T, rhs: T) -> Option<T> {
+        Some(lhs.saturating_add(rhs))
+    }
+
+    fn sub<T: Integer>(self, lhs: T, rhs: T) -> Option<T> {
+        Some(lhs.saturating_sub(rhs))
+    }
+
+    fn mul<T: Integer>(self, lhs: T, rhs: T) -> Option<T> {
+        Some(lhs.saturating_mul(rhs))
+    }
+
+    fn output<T>(self, value: Option<T>) -> Self::Output<T> {
+        match value {
+            Some(value) => value,
+            None => unreachable!(),
+        }
+    }
+}
+
+/// Detect overflow.
+///
+/// Items are yielded as [`Option`]s:
+/// once a result overflows,
+/// that item and every item after it is [`None`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Checked;
+
+impl policy::Sealed for Checked {}
+impl Policy for Checked {
+    type Output<T> = Option<T>;
+
+    fn add<T: Integer>(self, lhs: T, rhs: T) -> Option<T> {
+        lhs.checked_add(rhs)
+    }
+
+    fn sub<T: Integer>(self, lhs: T, rhs: T) -> Option<T> {
+        lhs.checked_sub(rhs)
+    }
+
+    fn mul<T: Integer>(self, lhs: T, rhs: T) -> Option<T> {
+        lhs.checked_mul(rhs)
+    }
+
+    fn output<T>(self, value: Option<T>) -> Self::Output<T> {
+        value
+    }
+}
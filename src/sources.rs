@@ -0,0 +1,71 @@
+//! Infinite iterators that generate well-known sequences.
+//!
+//! Sequences built from integer arithmetic take an overflow [`Policy`]
+//! deciding what happens once their items no longer fit in the integer type.
+//!
+//! [`Policy`]: crate::overflow::Policy
+
+mod chaotic_maps;
+pub use chaotic_maps::{logistic_map, tent_map, LogisticMap, TentMap};
+
+mod collatz;
+pub use collatz::{collatz, Collatz};
+
+mod convergents;
+pub use convergents::{convergents, Convergents};
+
+mod de_bruijn;
+pub use de_bruijn::{de_bruijn, DeBruijn};
+
+mod fibonacci;
+pub use fibonacci::{fibonacci, Fibonacci};
+#[cfg(feature = "bignum")]
+pub use fibonacci::{fibonacci_big, FibonacciBig};
+
+mod gray_codes;
+pub use gray_codes::{gray_codes, GrayCodes};
+
+mod linear_recurrence;
+pub use linear_recurrence::{linear_recurrence, LinearRecurrence};
+
+#[cfg(feature = "alloc")]
+mod look_and_say;
+#[cfg(feature = "alloc")]
+pub use look_and_say::{look_and_say, look_and_say_from, LookAndSay};
+
+mod low_discrepancy;
+pub use low_discrepancy::{halton, van_der_corput, Halton, VanDerCorput};
+
+#[cfg(feature = "alloc")]
+mod natural;
+#[cfg(feature = "bignum")]
+pub use natural::Natural;
+
+mod polygonal;
+pub use polygonal::{polygonal, triangular, Polygonal};
+
+#[cfg(feature = "alloc")]
+mod primes;
+#[cfg(feature = "alloc")]
+pub use primes::{primes, Primes};
+
+mod progression;
+pub use progression::{arithmetic, geometric, powers_of, Arithmetic, Geometric};
+#[cfg(feature = "bignum")]
+pub use progression::{powers_of_big, PowersOfBig};
+
+mod random;
+pub use random::{
+    pcg32, pcg32_with_stream, splitmix64, xorshift64star, Pcg32, SplitMix64, Xorshift64Star,
+};
+
+#[cfg(feature = "alloc")]
+mod spigot;
+#[cfg(feature = "alloc")]
+pub use spigot::{e_digits, pi_digits, EDigits, PiDigits};
+
+mod thue_morse;
+pub use thue_morse::{thue_morse, ThueMorse};
+
+mod wrapping_range;
+pub use wrapping_range::{wrapping_range_from, WrappingRangeFrom};
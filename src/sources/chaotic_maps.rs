@@ -0,0 +1,117 @@
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator over the orbit of `x0`
+/// under the logistic map `x ↦ r x (1 - x)`.
+///
+/// The first item is `x0` itself.
+/// For `x0` in `[0, 1]` and `r` in `[0, 4]` the orbit stays in `[0, 1]`;
+/// for most `r` above about 3.57 it is chaotic,
+/// so it can serve as cheap deterministic pseudo-random stimulus.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIterator, InfiniteIteratorExt};
+///
+/// let mut orbit = sources::logistic_map(4.0, 0.25);
+/// assert_eq!(orbit.next_array(), [0.25, 0.75, 0.75]);
+///
+/// // With `r` below 3 the orbit settles on a fixed point.
+/// let fixed_point = sources::logistic_map(2.5, 0.1).nth_infinite(100);
+/// assert!((fixed_point - 0.6).abs() < 1e-9);
+///
+/// let chaotic = sources::logistic_map(3.9, 0.1);
+/// assert!(chaotic.take_exact(1000).all(|x| (0.0..=1.0).contains(&x)));
+/// ```
+pub fn logistic_map(r: f64, x0: f64) -> LogisticMap {
+    LogisticMap { r, x: x0 }
+}
+
+/// An infinite iterator over an orbit of the logistic map.
+///
+/// This `struct` is created by [`logistic_map`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct LogisticMap {
+    r: f64,
+    /// The next item.
+    x: f64,
+}
+
+impl Iterator for LogisticMap {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl InfiniteIterator for LogisticMap {
+    fn next_infinite(&mut self) -> Self::Item {
+        let x = self.x;
+        self.x = self.r * x * (1.0 - x);
+        x
+    }
+}
+
+/// Create an infinite iterator over the orbit of `x0`
+/// under the tent map `x ↦ μ min(x, 1 - x)`.
+///
+/// The first item is `x0` itself.
+/// For `x0` in `[0, 1]` and `mu` in `[0, 2]` the orbit stays in `[0, 1]`,
+/// and it is chaotic for `mu` above one.
+///
+/// Beware that with `mu` exactly two,
+/// every step shifts one bit out of the binary representation of `x`,
+/// so in floating point every orbit collapses to zero
+/// after at most a few dozen steps.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut orbit = sources::tent_map(2.0, 0.375);
+/// assert_eq!(orbit.next_array(), [0.375, 0.75, 0.5, 1.0, 0.0, 0.0]);
+///
+/// let chaotic = sources::tent_map(1.99, 0.1);
+/// assert!(chaotic.take_exact(1000).all(|x| (0.0..=1.0).contains(&x)));
+/// ```
+pub fn tent_map(mu: f64, x0: f64) -> TentMap {
+    TentMap { mu, x: x0 }
+}
+
+/// An infinite iterator over an orbit of the tent map.
+///
+/// This `struct` is created by [`tent_map`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TentMap {
+    mu: f64,
+    /// The next item.
+    x: f64,
+}
+
+impl Iterator for TentMap {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl InfiniteIterator for TentMap {
+    fn next_infinite(&mut self) -> Self::Item {
+        let x = self.x;
+        self.x = self.mu * if x < 0.5 { x } else { 1.0 - x };
+        x
+    }
+}
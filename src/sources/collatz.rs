@@ -0,0 +1,80 @@
+use crate::overflow::{Integer, Policy};
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator over the Collatz orbit of `start`.
+///
+/// Each item after `start` is half the item before it if that is even,
+/// and three times it plus one if it is odd.
+/// Every positive starting point that has ever been tried
+/// eventually falls into the cycle `4, 2, 1`,
+/// so the orbit goes on forever.
+/// `policy` decides what happens when an item overflows;
+/// see the [`overflow`](crate::overflow) module.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIteratorExt};
+///
+/// let mut iter = sources::collatz(6_u32, overflow::Checked);
+/// assert_eq!(iter.next_array(), [6, 3, 10, 5, 16, 8, 4, 2, 1, 4, 2, 1].map(Some));
+/// ```
+///
+/// Combined with [`detect_cycle`](crate::InfiniteIteratorExt::detect_cycle),
+/// the number of steps before the orbit reaches the cycle:
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIteratorExt};
+///
+/// let orbit = sources::collatz(27_u32, overflow::Checked);
+/// assert_eq!(orbit.detect_cycle(), (109, 3));
+///
+/// // The orbit of 27 climbs much too high to fit in a byte.
+/// let mut orbit = sources::collatz(27_u8, overflow::Checked).skip(11);
+/// assert_eq!(orbit.next_array(), [Some(107), None]);
+/// ```
+pub fn collatz<T: Integer, P: Policy>(start: T, policy: P) -> Collatz<T, P> {
+    Collatz {
+        next: Some(start),
+        policy,
+    }
+}
+
+/// An infinite iterator over a Collatz orbit.
+///
+/// This `struct` is created by [`collatz`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Collatz<T, P> {
+    /// The next item, or `None` if it has overflowed.
+    next: Option<T>,
+    policy: P,
+}
+
+impl<T: Integer, P: Policy> Iterator for Collatz<T, P> {
+    type Item = P::Output<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T: Integer, P: Policy> InfiniteIterator for Collatz<T, P> {
+    fn next_infinite(&mut self) -> Self::Item {
+        let policy = self.policy;
+        let item = self.next;
+        self.next = item.and_then(|item| {
+            if item.is_even() {
+                Some(item.half())
+            } else {
+                let three = T::ONE.wrapping_add(T::ONE).wrapping_add(T::ONE);
+                policy.add(policy.mul(item, three)?, T::ONE)
+            }
+        });
+        policy.output(item)
+    }
+}
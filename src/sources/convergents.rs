@@ -0,0 +1,121 @@
+use crate::overflow::{Integer, Policy};
+use crate::InfiniteIterator;
+use crate::IntoInfiniteIterator;
+
+/// Create an infinite iterator over the convergents of a continued fraction,
+/// given its partial quotients `a₀, a₁, a₂, …`
+///
+/// The `n`th item is the fraction `(p, q)` equal to
+/// `a₀ + 1 / (a₁ + 1 / (… + 1 / aₙ))` in lowest terms.
+/// Each convergent is the best rational approximation
+/// to the value of the whole continued fraction
+/// among fractions with a denominator no greater than its own.
+///
+/// Numerators and denominators grow at least as fast as the Fibonacci numbers,
+/// so `policy` decides what happens when they overflow;
+/// see the [`overflow`](crate::overflow) module.
+///
+/// # Examples
+///
+/// The golden ratio, all of whose partial quotients are one,
+/// has ratios of consecutive Fibonacci numbers as its convergents:
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIteratorExt};
+///
+/// let mut golden = sources::convergents(core::iter::repeat(1_u32), overflow::Checked);
+/// assert_eq!(
+///     golden.next_array(),
+///     [(1, 1), (2, 1), (3, 2), (5, 3), (8, 5)].map(Some),
+/// );
+/// ```
+///
+/// The square root of two is `[1; 2, 2, 2, …]`:
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIteratorExt};
+///
+/// let quotients = core::iter::once(1_u8).chain(core::iter::repeat(2));
+/// let mut sqrt_2 = sources::convergents(quotients, overflow::Checked);
+/// assert_eq!(
+///     sqrt_2.next_array(),
+///     [
+///         Some((1, 1)),
+///         Some((3, 2)),
+///         Some((7, 5)),
+///         Some((17, 12)),
+///         Some((41, 29)),
+///         Some((99, 70)),
+///         Some((239, 169)),
+///         None,
+///     ],
+/// );
+/// ```
+pub fn convergents<I, P>(quotients: I, policy: P) -> Convergents<I::IntoInfiniteIter, P>
+where
+    I: IntoInfiniteIterator,
+    I::Item: Integer,
+    P: Policy,
+{
+    Convergents {
+        quotients: quotients.into_infinite_iter(),
+        state: Some([I::Item::ONE, I::Item::ZERO, I::Item::ZERO, I::Item::ONE]),
+        policy,
+    }
+}
+
+/// An infinite iterator over the convergents of a continued fraction.
+///
+/// This `struct` is created by [`convergents`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Convergents<I: Iterator, P> {
+    quotients: I,
+    /// The previous two convergents as `[p₁, q₁, p₂, q₂]`,
+    /// most recent first,
+    /// or `None` if they have overflowed.
+    state: Option<[I::Item; 4]>,
+    policy: P,
+}
+
+impl<I: Iterator, P> Convergents<I, P> {
+    /// Extract the underlying iterator of partial quotients.
+    pub fn into_inner(self) -> I {
+        self.quotients
+    }
+}
+
+impl<I, P> Iterator for Convergents<I, P>
+where
+    I: InfiniteIterator,
+    I::Item: Integer,
+    P: Policy,
+{
+    type Item = P::Output<(I::Item, I::Item)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.quotients.size_hint()
+    }
+}
+
+impl<I, P> InfiniteIterator for Convergents<I, P>
+where
+    I: InfiniteIterator,
+    I::Item: Integer,
+    P: Policy,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        let policy = self.policy;
+        let quotient = self.quotients.next_infinite();
+        self.state = self.state.and_then(|[p_1, q_1, p_2, q_2]| {
+            let p = policy.add(policy.mul(quotient, p_1)?, p_2)?;
+            let q = policy.add(policy.mul(quotient, q_1)?, q_2)?;
+            Some([p, q, p_1, q_1])
+        });
+        policy.output(self.state.map(|[p, q, _, _]| (p, q)))
+    }
+}
@@ -0,0 +1,125 @@
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator that cycles forever through
+/// the de Bruijn sequence B(`k`, `N`):
+/// the shortest cyclic sequence of symbols `0..k`
+/// containing every word of length `N` exactly once.
+///
+/// Each period is `k`<sup>`N`</sup> symbols long,
+/// and the sequence is the lexicographically smallest de Bruijn sequence.
+/// It is generated lazily by concatenating Lyndon words
+/// in constant memory and without allocation,
+/// so it is suitable for exhaustively exercising
+/// every `N`-symbol pattern on small targets.
+///
+/// # Panics
+///
+/// Panics if `k` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut bits = sources::de_bruijn::<3>(2);
+/// assert_eq!(bits.next_array(), [0, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 1]);
+///
+/// let mut ternary = sources::de_bruijn::<2>(3);
+/// assert_eq!(ternary.next_array(), [0, 0, 1, 0, 2, 1, 1, 2, 2]);
+/// ```
+///
+/// Every word appears once in each period:
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+/// use std::collections::HashSet;
+///
+/// let mut windows = sources::de_bruijn::<4>(3).windows::<4>();
+/// let words: HashSet<_> = windows.collect_n(81);
+/// assert_eq!(words.len(), 81);
+/// ```
+///
+/// Words of length zero are rejected at compile time:
+///
+/// ```compile_fail
+/// let iter = infinite_iterator::sources::de_bruijn::<0>(2);
+/// ```
+pub fn de_bruijn<const N: usize>(k: usize) -> DeBruijn<N> {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertNonEmpty::<N>::OK;
+    assert!(k != 0, "de Bruijn sequences need at least one symbol");
+    DeBruijn {
+        word: [0; N],
+        len: 1,
+        pos: 0,
+        k,
+    }
+}
+
+struct AssertNonEmpty<const N: usize>;
+impl<const N: usize> AssertNonEmpty<N> {
+    const OK: () = assert!(N != 0, "de Bruijn words must be non-empty");
+}
+
+/// An infinite iterator cycling through a de Bruijn sequence.
+///
+/// This `struct` is created by [`de_bruijn`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DeBruijn<const N: usize> {
+    /// The Lyndon word currently being yielded is `word[..len]`.
+    word: [usize; N],
+    len: usize,
+    /// The index in the word of the next item.
+    pos: usize,
+    k: usize,
+}
+
+impl<const N: usize> DeBruijn<N> {
+    /// Move on to the next Lyndon word whose length divides `N`,
+    /// wrapping back around to the first after the last.
+    fn next_word(&mut self) {
+        loop {
+            for i in self.len..N {
+                self.word[i] = self.word[i - self.len];
+            }
+            self.len = N;
+            while self.len > 0 && self.word[self.len - 1] == self.k - 1 {
+                self.len -= 1;
+            }
+            if self.len == 0 {
+                self.word[0] = 0;
+                self.len = 1;
+            } else {
+                self.word[self.len - 1] += 1;
+            }
+            if N % self.len == 0 {
+                break;
+            }
+        }
+    }
+}
+
+impl<const N: usize> Iterator for DeBruijn<N> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<const N: usize> InfiniteIterator for DeBruijn<N> {
+    fn next_infinite(&mut self) -> Self::Item {
+        let item = self.word[self.pos];
+        self.pos += 1;
+        if self.pos == self.len {
+            self.pos = 0;
+            self.next_word();
+        }
+        item
+    }
+}
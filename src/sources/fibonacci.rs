@@ -0,0 +1,131 @@
+#[cfg(feature = "bignum")]
+use super::natural::Natural;
+use crate::overflow::{Integer, Policy};
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator over the Fibonacci numbers
+/// `0, 1, 1, 2, 3, 5, 8, …`
+///
+/// The sequence grows quickly,
+/// so `policy` decides what happens when the items overflow;
+/// see the [`overflow`](crate::overflow) module.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIteratorExt};
+///
+/// let mut iter = sources::fibonacci::<u64, _>(overflow::Wrapping);
+/// assert_eq!(iter.next_array(), [0, 1, 1, 2, 3, 5, 8, 13]);
+///
+/// // The 13th Fibonacci number is the largest that fits in a `u8`.
+/// let mut iter = sources::fibonacci::<u8, _>(overflow::Checked).skip(13);
+/// assert_eq!(iter.next_array(), [Some(233), None, None]);
+///
+/// let mut iter = sources::fibonacci::<u8, _>(overflow::Saturating).skip(13);
+/// assert_eq!(iter.next_array(), [233, 255, 255]);
+/// ```
+pub fn fibonacci<T: Integer, P: Policy>(policy: P) -> Fibonacci<T, P> {
+    Fibonacci {
+        current: Some(T::ZERO),
+        next: Some(T::ONE),
+        policy,
+    }
+}
+
+/// An infinite iterator over the Fibonacci numbers.
+///
+/// This `struct` is created by [`fibonacci`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Fibonacci<T, P> {
+    /// The next item, or `None` if it has overflowed.
+    current: Option<T>,
+    /// The item after that, or `None` if it has overflowed.
+    next: Option<T>,
+    policy: P,
+}
+
+impl<T: Integer, P: Policy> Iterator for Fibonacci<T, P> {
+    type Item = P::Output<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T: Integer, P: Policy> InfiniteIterator for Fibonacci<T, P> {
+    fn next_infinite(&mut self) -> Self::Item {
+        let policy = self.policy;
+        let after = match (self.current, self.next) {
+            (Some(current), Some(next)) => policy.add(current, next),
+            _ => None,
+        };
+        let item = core::mem::replace(&mut self.current, self.next);
+        self.next = after;
+        policy.output(item)
+    }
+}
+
+/// Create an infinite iterator over the Fibonacci numbers
+/// as arbitrary-precision [`Natural`]s, so the items never overflow.
+///
+/// Each item is about 0.7 bits longer than the last,
+/// so the time and memory taken per item
+/// grow linearly with the number of items yielded so far.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut iter = sources::fibonacci_big();
+/// assert_eq!(iter.next_array().map(|n| n.to_string()), ["0", "1", "1", "2", "3", "5"]);
+///
+/// let f_100 = sources::fibonacci_big().nth_infinite(100);
+/// assert_eq!(f_100.to_string(), "354224848179261915075");
+/// ```
+#[cfg(feature = "bignum")]
+pub fn fibonacci_big() -> FibonacciBig {
+    FibonacciBig {
+        current: Natural::from(0),
+        next: Natural::from(1),
+    }
+}
+
+/// An infinite iterator over the Fibonacci numbers as [`Natural`]s.
+///
+/// This `struct` is created by [`fibonacci_big`].
+#[cfg(feature = "bignum")]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FibonacciBig {
+    current: Natural,
+    next: Natural,
+}
+
+#[cfg(feature = "bignum")]
+impl Iterator for FibonacciBig {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(feature = "bignum")]
+impl InfiniteIterator for FibonacciBig {
+    fn next_infinite(&mut self) -> Self::Item {
+        let after = self.current.add(&self.next);
+        let next = core::mem::replace(&mut self.next, after);
+        core::mem::replace(&mut self.current, next)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator cycling forever through
+/// the `N`-bit reflected binary Gray codes.
+///
+/// Consecutive items differ in exactly one bit,
+/// including the last code of each cycle and the first code of the next,
+/// and each cycle visits all 2<sup>`N`</sup> codes.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut codes = sources::gray_codes::<3>();
+/// assert_eq!(
+///     codes.next_array(),
+///     [0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100, 0b000],
+/// );
+///
+/// let codes = sources::gray_codes::<16>();
+/// assert!(codes
+///     .pairwise()
+///     .take(1 << 17)
+///     .all(|(a, b)| (a ^ b).count_ones() == 1));
+/// ```
+///
+/// Codes wider than 64 bits are rejected at compile time:
+///
+/// ```compile_fail
+/// let iter = infinite_iterator::sources::gray_codes::<65>();
+/// ```
+pub fn gray_codes<const N: usize>() -> GrayCodes<N> {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertFitsInU64::<N>::OK;
+    GrayCodes { index: 0 }
+}
+
+struct AssertFitsInU64<const N: usize>;
+impl<const N: usize> AssertFitsInU64<N> {
+    const OK: () = assert!(N <= 64, "Gray codes must fit in a `u64`");
+}
+
+/// An infinite iterator cycling through Gray codes.
+///
+/// This `struct` is created by [`gray_codes`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct GrayCodes<const N: usize> {
+    /// The position of the next code in the cycle.
+    index: u64,
+}
+
+impl<const N: usize> GrayCodes<N> {
+    const MASK: u64 = if N == 64 { u64::MAX } else { (1 << N) - 1 };
+}
+
+impl<const N: usize> Iterator for GrayCodes<N> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.wrapping_add(n as u64) & Self::MASK;
+        self.next()
+    }
+}
+
+impl<const N: usize> InfiniteIterator for GrayCodes<N> {
+    fn next_infinite(&mut self) -> Self::Item {
+        let code = self.index ^ (self.index >> 1);
+        self.index = self.index.wrapping_add(1) & Self::MASK;
+        code
+    }
+}
@@ -0,0 +1,109 @@
+use crate::overflow::{Integer, Policy};
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator over a linear recurrence of order `K`.
+///
+/// The first `K` items are `initial_terms`,
+/// and every item after that is
+/// `c₀ aₙ₋₁ + c₁ aₙ₋₂ + … + cₖ₋₁ aₙ₋ₖ`,
+/// where `c` is `coefficients`
+/// and `aₙ₋₁` is the item immediately before.
+/// `policy` decides what happens when the items overflow;
+/// see the [`overflow`](crate::overflow) module.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIteratorExt};
+///
+/// let mut lucas = sources::linear_recurrence([1, 1], [2_u32, 1], overflow::Wrapping);
+/// assert_eq!(lucas.next_array(), [2, 1, 3, 4, 7, 11, 18, 29]);
+///
+/// let mut pell = sources::linear_recurrence([2, 1], [0_u32, 1], overflow::Wrapping);
+/// assert_eq!(pell.next_array(), [0, 1, 2, 5, 12, 29, 70, 169]);
+///
+/// let mut tribonacci = sources::linear_recurrence([1; 3], [0_u32, 0, 1], overflow::Wrapping);
+/// assert_eq!(tribonacci.next_array(), [0, 0, 1, 1, 2, 4, 7, 13, 24]);
+///
+/// // Coefficients may be negative:
+/// // aₙ = aₙ₋₁ - aₙ₋₂ repeats every six items.
+/// let mut periodic = sources::linear_recurrence([1, -1], [1_i8, 1], overflow::Checked);
+/// assert_eq!(periodic.next_array(), [1, 1, 0, -1, -1, 0, 1, 1].map(Some));
+/// ```
+///
+/// Items that were computed before an overflow are still yielded:
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIteratorExt};
+///
+/// let mut iter = sources::linear_recurrence([100, 0], [1_u8, 2], overflow::Checked);
+/// assert_eq!(iter.next_array(), [Some(1), Some(2), Some(200), None, None]);
+/// ```
+///
+/// Recurrences of order zero are rejected at compile time:
+///
+/// ```compile_fail
+/// use infinite_iterator::{overflow, sources};
+///
+/// let iter = sources::linear_recurrence::<u32, _, 0>([], [], overflow::Wrapping);
+/// ```
+pub fn linear_recurrence<T: Integer, P: Policy, const K: usize>(
+    coefficients: [T; K],
+    initial_terms: [T; K],
+    policy: P,
+) -> LinearRecurrence<T, P, K> {
+    #[allow(clippy::let_unit_value)]
+    let () = AssertNonEmpty::<K>::OK;
+    LinearRecurrence {
+        coefficients,
+        terms: initial_terms.map(Some),
+        policy,
+    }
+}
+
+struct AssertNonEmpty<const K: usize>;
+impl<const K: usize> AssertNonEmpty<K> {
+    const OK: () = assert!(K != 0, "linear recurrences must have order at least one");
+}
+
+/// An infinite iterator over a linear recurrence.
+///
+/// This `struct` is created by [`linear_recurrence`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct LinearRecurrence<T, P, const K: usize> {
+    coefficients: [T; K],
+    /// The next `K` items, oldest first,
+    /// each `None` if it has overflowed.
+    terms: [Option<T>; K],
+    policy: P,
+}
+
+impl<T: Integer, P: Policy, const K: usize> Iterator for LinearRecurrence<T, P, K> {
+    type Item = P::Output<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T: Integer, P: Policy, const K: usize> InfiniteIterator for LinearRecurrence<T, P, K> {
+    fn next_infinite(&mut self) -> Self::Item {
+        let policy = self.policy;
+        let next = self
+            .coefficients
+            .iter()
+            .zip(self.terms.iter().rev())
+            .try_fold(T::ZERO, |sum, (&coefficient, &term)| {
+                policy.add(sum, policy.mul(coefficient, term?)?)
+            });
+        let item = self.terms[0];
+        self.terms.rotate_left(1);
+        self.terms[K - 1] = next;
+        policy.output(item)
+    }
+}
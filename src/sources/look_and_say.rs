@@ -0,0 +1,110 @@
+use crate::InfiniteIterator;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Create an infinite iterator over the look-and-say sequence
+/// `1, 11, 21, 1211, 111221, …`
+///
+/// Each term describes the runs of digits in the term before:
+/// `1211` is "one 1, one 2, two 1s", giving `111221`.
+/// Terms are yielded as vectors of digits,
+/// each a freshly allocated buffer that the caller owns.
+/// They grow by about 30% each time.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut terms = sources::look_and_say();
+/// assert_eq!(terms.next_array(), [vec![1], vec![1, 1], vec![2, 1], vec![1, 2, 1, 1]]);
+///
+/// let mut strings = sources::look_and_say()
+///     .map_infinite(|digits| digits.into_iter().map(|d| char::from(b'0' + d)).collect::<String>());
+/// assert_eq!(strings.nth_infinite(5), "312211");
+/// ```
+pub fn look_and_say() -> LookAndSay {
+    look_and_say_from(vec![1])
+}
+
+/// Create an infinite iterator over the look-and-say sequence
+/// starting from the given digits.
+///
+/// Runs longer than nine digits are described
+/// using the decimal digits of their length.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut terms = sources::look_and_say_from(vec![3, 3, 3]);
+/// assert_eq!(terms.next_array(), [vec![3, 3, 3], vec![3, 3], vec![2, 3], vec![1, 2, 1, 3]]);
+///
+/// // 22 describes itself.
+/// let mut terms = sources::look_and_say_from(vec![2, 2]);
+/// assert_eq!(terms.next_array(), [vec![2, 2], vec![2, 2], vec![2, 2]]);
+///
+/// let mut terms = sources::look_and_say_from(vec![7; 12]);
+/// assert_eq!(terms.nth_infinite(1), [1, 2, 7]);
+/// ```
+pub fn look_and_say_from(seed: Vec<u8>) -> LookAndSay {
+    LookAndSay { term: seed }
+}
+
+/// An infinite iterator over the look-and-say sequence.
+///
+/// This `struct` is created by [`look_and_say`] and [`look_and_say_from`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct LookAndSay {
+    /// The next term.
+    term: Vec<u8>,
+}
+
+impl LookAndSay {
+    /// Get a reference to the term that will be yielded next.
+    pub fn peek(&self) -> &[u8] {
+        &self.term
+    }
+}
+
+impl Iterator for LookAndSay {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl InfiniteIterator for LookAndSay {
+    fn next_infinite(&mut self) -> Self::Item {
+        let mut next = Vec::with_capacity(self.term.len() + self.term.len() / 3 + 2);
+        let mut rest = &self.term[..];
+        while let Some(&digit) = rest.first() {
+            let len = rest.iter().take_while(|&&d| d == digit).count();
+            push_decimal(&mut next, len);
+            next.push(digit);
+            rest = &rest[len..];
+        }
+        core::mem::replace(&mut self.term, next)
+    }
+}
+
+/// Append the decimal digits of `n`, most significant first.
+fn push_decimal(digits: &mut Vec<u8>, n: usize) {
+    let start = digits.len();
+    let mut n = n;
+    loop {
+        digits.push((n % 10) as u8);
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    digits[start..].reverse();
+}
@@ -0,0 +1,166 @@
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator over the van der Corput sequence in `base`,
+/// a low-discrepancy sequence of points in the interval `[0, 1)`.
+///
+/// The `n`th item, counting from one,
+/// is `n` written in `base` with its digits reflected about the radix point,
+/// so the points fill the interval evenly as more are taken
+/// rather than clumping like uniform random samples.
+///
+/// # Panics
+///
+/// Panics if `base` is less than two.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut points = sources::van_der_corput(2);
+/// assert_eq!(points.next_array(), [0.5, 0.25, 0.75, 0.125, 0.625, 0.375, 0.875]);
+/// ```
+///
+/// Quasi-Monte Carlo estimation of `∫₀¹ x² dx = 1/3`:
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let n = 4096;
+/// let sum: f64 = sources::van_der_corput(3).take_exact(n).map(|x| x * x).sum();
+/// assert!((sum / n as f64 - 1.0 / 3.0).abs() < 1e-3);
+/// ```
+pub fn van_der_corput(base: u32) -> VanDerCorput {
+    assert!(base >= 2, "base must be at least two");
+    VanDerCorput { index: 1, base }
+}
+
+/// An infinite iterator over the van der Corput sequence.
+///
+/// This `struct` is created by [`van_der_corput`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct VanDerCorput {
+    /// The index of the next point.
+    index: u64,
+    base: u32,
+}
+
+impl Iterator for VanDerCorput {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.wrapping_add(n as u64);
+        self.next()
+    }
+}
+
+impl InfiniteIterator for VanDerCorput {
+    fn next_infinite(&mut self) -> Self::Item {
+        let point = radical_inverse(self.index, self.base);
+        self.index = self.index.wrapping_add(1);
+        point
+    }
+}
+
+/// Create an infinite iterator over the Halton sequence with the given bases,
+/// a low-discrepancy sequence of points in the unit hypercube `[0, 1)ᴺ`.
+///
+/// Each coordinate of the points follows the [`van_der_corput`] sequence
+/// in the corresponding base.
+/// The bases should be pairwise coprime, and are usually the first `N` primes;
+/// otherwise the coordinates are correlated
+/// and the points do not fill the hypercube.
+///
+/// # Panics
+///
+/// Panics if any base is less than two.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut points = sources::halton([2, 3]);
+/// assert_eq!(
+///     points.next_array(),
+///     [[0.5, 1.0 / 3.0], [0.25, 2.0 / 3.0], [0.75, 1.0 / 9.0], [0.125, 4.0 / 9.0]],
+/// );
+/// ```
+///
+/// Estimating π from the fraction of points that land in a quarter circle:
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let n = 10_000;
+/// let inside = sources::halton([2, 3])
+///     .take_exact(n)
+///     .filter(|[x, y]| x * x + y * y < 1.0)
+///     .count();
+/// assert!((4.0 * inside as f64 / n as f64 - core::f64::consts::PI).abs() < 1e-2);
+/// ```
+pub fn halton<const N: usize>(bases: [u32; N]) -> Halton<N> {
+    assert!(
+        bases.iter().all(|&base| base >= 2),
+        "bases must be at least two"
+    );
+    Halton { index: 1, bases }
+}
+
+/// An infinite iterator over the Halton sequence.
+///
+/// This `struct` is created by [`halton`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Halton<const N: usize> {
+    /// The index of the next point.
+    index: u64,
+    bases: [u32; N],
+}
+
+impl<const N: usize> Iterator for Halton<N> {
+    type Item = [f64; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.wrapping_add(n as u64);
+        self.next()
+    }
+}
+
+impl<const N: usize> InfiniteIterator for Halton<N> {
+    fn next_infinite(&mut self) -> Self::Item {
+        let index = self.index;
+        self.index = self.index.wrapping_add(1);
+        self.bases.map(|base| radical_inverse(index, base))
+    }
+}
+
+/// Reflect the digits of `n` in `base` about the radix point.
+fn radical_inverse(mut n: u64, base: u32) -> f64 {
+    let base = u64::from(base);
+    let mut scale = 1.0;
+    let mut result = 0.0;
+    while n != 0 {
+        scale /= base as f64;
+        result += scale * (n % base) as f64;
+        n /= base;
+    }
+    result
+}
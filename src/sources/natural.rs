@@ -0,0 +1,169 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// An arbitrary-precision natural number.
+///
+/// This is the item type of the sources
+/// whose items would overflow any fixed-width integer,
+/// such as [`fibonacci_big`](super::fibonacci_big)
+/// and [`powers_of_big`](super::powers_of_big).
+/// It supports only what is needed to inspect those items:
+/// comparison, hashing, formatting in decimal, and access to its limbs.
+///
+/// This type is only available with the unstable `bignum` feature.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Natural(Vec<u64>);
+
+impl From<u64> for Natural {
+    fn from(n: u64) -> Self {
+        let mut limbs = Vec::new();
+        if n != 0 {
+            limbs.push(n);
+        }
+        Self(limbs)
+    }
+}
+
+impl Natural {
+    /// The little-endian base-2⁶⁴ limbs of this number,
+    /// with no trailing zeros.
+    ///
+    /// Zero therefore has no limbs at all.
+    #[cfg(feature = "bignum")]
+    #[must_use]
+    pub fn limbs(&self) -> &[u64] {
+        &self.0
+    }
+
+    pub(super) fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(super) fn mul_small(&self, rhs: u64) -> Self {
+        if rhs == 0 {
+            return Self(Vec::new());
+        }
+        let mut limbs = Vec::with_capacity(self.0.len() + 1);
+        let mut carry = 0;
+        for &limb in &self.0 {
+            let product = u128::from(limb) * u128::from(rhs) + carry;
+            limbs.push(product as u64);
+            carry = product >> 64;
+        }
+        if carry != 0 {
+            limbs.push(carry as u64);
+        }
+        Self(limbs)
+    }
+
+    pub(super) fn add(&self, rhs: &Self) -> Self {
+        let (long, short) = if self.0.len() >= rhs.0.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let mut limbs = Vec::with_capacity(long.0.len() + 1);
+        let mut carry = false;
+        for (i, &limb) in long.0.iter().enumerate() {
+            let (sum, overflow_1) = limb.overflowing_add(short.0.get(i).copied().unwrap_or(0));
+            let (sum, overflow_2) = sum.overflowing_add(u64::from(carry));
+            limbs.push(sum);
+            carry = overflow_1 || overflow_2;
+        }
+        if carry {
+            limbs.push(1);
+        }
+        Self(limbs)
+    }
+
+    /// Subtract `rhs`, which must be no greater than `self`.
+    pub(super) fn sub(&self, rhs: &Self) -> Self {
+        let mut limbs = Vec::with_capacity(self.0.len());
+        let mut borrow = false;
+        for (i, &limb) in self.0.iter().enumerate() {
+            let (difference, overflow_1) = limb.overflowing_sub(rhs.0.get(i).copied().unwrap_or(0));
+            let (difference, overflow_2) = difference.overflowing_sub(u64::from(borrow));
+            limbs.push(difference);
+            borrow = overflow_1 || overflow_2;
+        }
+        debug_assert!(!borrow);
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self(limbs)
+    }
+
+    /// Compute `⌊self / rhs⌋`, which must fit in a `u64`.
+    pub(super) fn div_floor(&self, rhs: &Self) -> u64 {
+        // The quotient is always small, so find it by exponential search.
+        let mut high = 1;
+        while rhs.mul_small(high) <= *self {
+            high *= 2;
+        }
+        let mut low = high / 2;
+        while high - low > 1 {
+            let middle = low + (high - low) / 2;
+            if rhs.mul_small(middle) <= *self {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        low
+    }
+
+    /// Divide by a nonzero `rhs` in place, returning the remainder.
+    fn div_rem_small(&mut self, rhs: u64) -> u64 {
+        let mut remainder = 0;
+        for limb in self.0.iter_mut().rev() {
+            let dividend = u128::from(remainder) << 64 | u128::from(*limb);
+            *limb = (dividend / u128::from(rhs)) as u64;
+            remainder = (dividend % u128::from(rhs)) as u64;
+        }
+        while self.0.last() == Some(&0) {
+            self.0.pop();
+        }
+        remainder
+    }
+}
+
+impl PartialOrd for Natural {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Natural {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .len()
+            .cmp(&other.0.len())
+            .then_with(|| self.0.iter().rev().cmp(other.0.iter().rev()))
+    }
+}
+
+impl Display for Natural {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        // Split into base-10¹⁹ chunks, the largest power of ten fitting in a limb.
+        const CHUNK: u64 = 10_000_000_000_000_000_000;
+        let mut rest = self.clone();
+        let mut chunks = Vec::new();
+        while !rest.is_zero() {
+            chunks.push(rest.div_rem_small(CHUNK));
+        }
+        let mut chunks = chunks.iter().rev();
+        let first = chunks.next().copied().unwrap_or(0);
+        let mut digits = alloc::format!("{first}");
+        for chunk in chunks {
+            digits.push_str(&alloc::format!("{chunk:019}"));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+impl Debug for Natural {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::overflow::{Integer, Policy};
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator over the triangular numbers
+/// `0, 1, 3, 6, 10, 15, …`
+///
+/// The `n`th item is the sum of the first `n` positive integers.
+/// This is [`polygonal`] with three sides.
+/// `policy` decides what happens when the items overflow;
+/// see the [`overflow`](crate::overflow) module.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIterator, InfiniteIteratorExt};
+///
+/// let mut iter = sources::triangular::<u32, _>(overflow::Checked);
+/// assert_eq!(iter.next_array(), [0, 1, 3, 6, 10, 15].map(Some));
+///
+/// let mut iter = sources::triangular::<u64, _>(overflow::Wrapping);
+/// assert_eq!(iter.nth_infinite(100), 5050);
+/// ```
+pub fn triangular<T: Integer, P: Policy>(policy: P) -> Polygonal<T, P> {
+    let three = T::ONE.wrapping_add(T::ONE).wrapping_add(T::ONE);
+    polygonal(three, policy)
+}
+
+/// Create an infinite iterator over the polygonal numbers with `sides` sides,
+/// starting from zero.
+///
+/// The `n`th item is the number of dots in a regular polygon
+/// with `sides` sides and `n` dots along each side,
+/// so three sides gives the triangular numbers,
+/// four gives the squares, and so on.
+/// `policy` decides what happens when the items overflow;
+/// see the [`overflow`](crate::overflow) module.
+///
+/// # Panics
+///
+/// Panics if `sides` is less than two.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIteratorExt};
+///
+/// let mut squares = sources::polygonal(4_u8, overflow::Saturating);
+/// assert_eq!(squares.next_array(), [0, 1, 4, 9, 16, 25]);
+///
+/// let mut pentagonal = sources::polygonal(5_u8, overflow::Checked).skip(12);
+/// assert_eq!(pentagonal.next_array(), [Some(210), Some(247), None]);
+/// ```
+pub fn polygonal<T: Integer, P: Policy>(sides: T, policy: P) -> Polygonal<T, P> {
+    let two = T::ONE.wrapping_add(T::ONE);
+    assert!(sides >= two, "polygons must have at least two sides");
+    Polygonal {
+        next: Some(T::ZERO),
+        difference: Some(T::ONE),
+        step: sides.wrapping_sub(two),
+        policy,
+    }
+}
+
+/// An infinite iterator over polygonal numbers.
+///
+/// This `struct` is created by [`polygonal`] and [`triangular`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Polygonal<T, P> {
+    /// The next item, or `None` if it has overflowed.
+    next: Option<T>,
+    /// The difference between the next item and the one after,
+    /// or `None` if it has overflowed.
+    difference: Option<T>,
+    /// How much the difference grows by each time.
+    step: T,
+    policy: P,
+}
+
+impl<T: Integer, P: Policy> Iterator for Polygonal<T, P> {
+    type Item = P::Output<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T: Integer, P: Policy> InfiniteIterator for Polygonal<T, P> {
+    fn next_infinite(&mut self) -> Self::Item {
+        let policy = self.policy;
+        let item = self.next;
+        self.next = match (item, self.difference) {
+            (Some(item), Some(difference)) => policy.add(item, difference),
+            _ => None,
+        };
+        self.difference = self
+            .difference
+            .and_then(|difference| policy.add(difference, self.step));
+        policy.output(item)
+    }
+}
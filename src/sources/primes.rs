@@ -0,0 +1,136 @@
+use crate::InfiniteIterator;
+use alloc::boxed::Box;
+use alloc::collections::BinaryHeap;
+use core::cmp::Reverse;
+
+/// Create an infinite iterator over the prime numbers `2, 3, 5, 7, 11, …`
+///
+/// This uses an incremental sieve of Eratosthenes.
+/// Each odd prime `p` starts crossing off its odd multiples
+/// only once the candidates reach `p²`,
+/// with the primes to sieve by coming from a second, nested sieve.
+/// So after yielding the `n`th prime `pₙ`,
+/// memory use is proportional to the number of primes no greater than `√pₙ`.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIterator, InfiniteIteratorExt};
+///
+/// let mut primes = sources::primes();
+/// assert_eq!(primes.next_array(), [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+///
+/// assert_eq!(sources::primes().nth_infinite(999), 7919);
+/// ```
+pub fn primes() -> Primes {
+    Primes {
+        candidate: 2,
+        composites: BinaryHeap::new(),
+        base: None,
+    }
+}
+
+/// An infinite iterator over the prime numbers.
+///
+/// This `struct` is created by [`primes`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Primes {
+    /// The next number to test for primality.
+    candidate: u64,
+    /// Pairs of the next odd composite to be crossed off by each odd prime
+    /// whose square has been reached,
+    /// and twice that prime.
+    composites: BinaryHeap<Reverse<(u64, u64)>>,
+    /// The primes not yet added to `composites`, created once the candidates reach 9.
+    base: Option<BasePrimes>,
+}
+
+#[derive(Debug, Clone)]
+struct BasePrimes {
+    /// The primes after `prime`.
+    primes: Box<Primes>,
+    /// The smallest prime not yet added to `composites`.
+    prime: u64,
+    /// The square of `prime`,
+    /// or `u64::MAX` if that does not fit.
+    square: u64,
+}
+
+impl BasePrimes {
+    fn new() -> Self {
+        let mut primes = Box::new(primes());
+        // Two is skipped by only testing odd candidates.
+        primes.next_infinite();
+        let mut this = Self {
+            primes,
+            prime: 0,
+            square: 0,
+        };
+        this.advance();
+        this
+    }
+
+    fn advance(&mut self) {
+        self.prime = self.primes.next_infinite();
+        self.square = self.prime.saturating_mul(self.prime);
+    }
+}
+
+impl Iterator for Primes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl InfiniteIterator for Primes {
+    fn next_infinite(&mut self) -> Self::Item {
+        match self.candidate {
+            2 => {
+                self.candidate = 3;
+                return 2;
+            }
+            // Below 9, every odd number besides 1 is prime.
+            3 | 5 | 7 => {
+                let prime = self.candidate;
+                self.candidate += 2;
+                return prime;
+            }
+            _ => {}
+        }
+        let base = self.base.get_or_insert_with(BasePrimes::new);
+        loop {
+            let candidate = self.candidate;
+            self.candidate += 2;
+
+            let mut composite = false;
+            while let Some(mut top) = self.composites.peek_mut() {
+                let Reverse((multiple, step)) = *top;
+                if multiple != candidate {
+                    break;
+                }
+                composite = true;
+                *top = Reverse((multiple + step, step));
+            }
+
+            if candidate == base.square {
+                // Smaller multiples of this prime have smaller prime factors,
+                // so they are crossed off already.
+                let step = base.prime * 2;
+                self.composites.push(Reverse((candidate + step, step)));
+                base.advance();
+                composite = true;
+            }
+
+            if !composite {
+                break candidate;
+            }
+        }
+    }
+}
@@ -0,0 +1,218 @@
+#[cfg(feature = "bignum")]
+use super::natural::Natural;
+use crate::overflow::{Integer, Policy};
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator over the arithmetic progression
+/// `start`, `start + step`, `start + 2 * step`, …
+///
+/// `policy` decides what happens when the items overflow;
+/// see the [`overflow`](crate::overflow) module.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIterator, InfiniteIteratorExt};
+///
+/// let mut iter = sources::arithmetic(250_u8, 2, overflow::Wrapping);
+/// assert_eq!(iter.next_array(), [250, 252, 254, 0, 2]);
+///
+/// let mut iter = sources::arithmetic(250_u8, 2, overflow::Saturating);
+/// assert_eq!(iter.next_array(), [250, 252, 254, 255, 255]);
+///
+/// let mut iter = sources::arithmetic(250_u8, 2, overflow::Checked);
+/// assert_eq!(iter.next_array(), [Some(250), Some(252), Some(254), None, None]);
+/// ```
+///
+/// Counting down:
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIteratorExt};
+///
+/// let mut iter = sources::arithmetic(10_i32, -3, overflow::Checked);
+/// assert_eq!(iter.next_array(), [Some(10), Some(7), Some(4), Some(1), Some(-2)]);
+/// ```
+pub fn arithmetic<T: Integer, P: Policy>(start: T, step: T, policy: P) -> Arithmetic<T, P> {
+    Arithmetic {
+        next: Some(start),
+        step,
+        policy,
+    }
+}
+
+/// An infinite iterator over an arithmetic progression.
+///
+/// This `struct` is created by [`arithmetic`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Arithmetic<T, P> {
+    /// The next item, or `None` if it has overflowed.
+    next: Option<T>,
+    step: T,
+    policy: P,
+}
+
+impl<T: Integer, P: Policy> Iterator for Arithmetic<T, P> {
+    type Item = P::Output<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T: Integer, P: Policy> InfiniteIterator for Arithmetic<T, P> {
+    fn next_infinite(&mut self) -> Self::Item {
+        let (policy, step) = (self.policy, self.step);
+        let item = self.next;
+        self.next = item.and_then(|item| policy.add(item, step));
+        policy.output(item)
+    }
+}
+
+/// Create an infinite iterator over the geometric progression
+/// `start`, `start * ratio`, `start * ratio * ratio`, …
+///
+/// `policy` decides what happens when the items overflow;
+/// see the [`overflow`](crate::overflow) module.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIteratorExt};
+///
+/// let mut iter = sources::geometric(1_u8, 3, overflow::Checked);
+/// assert_eq!(iter.next_array(), [Some(1), Some(3), Some(9), Some(27), Some(81), Some(243), None]);
+///
+/// let mut iter = sources::geometric(1_u8, 3, overflow::Saturating);
+/// assert_eq!(iter.next_array(), [1, 3, 9, 27, 81, 243, 255]);
+/// ```
+pub fn geometric<T: Integer, P: Policy>(start: T, ratio: T, policy: P) -> Geometric<T, P> {
+    Geometric {
+        next: Some(start),
+        ratio,
+        policy,
+    }
+}
+
+/// Create an infinite iterator over the powers of `base`:
+/// `1`, `base`, `base * base`, …
+///
+/// This is [`geometric`] starting from one.
+/// `policy` decides what happens when the items overflow;
+/// see the [`overflow`](crate::overflow) module.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{overflow, sources, InfiniteIteratorExt};
+///
+/// let mut iter = sources::powers_of(2_u8, overflow::Wrapping);
+/// assert_eq!(iter.next_array(), [1, 2, 4, 8, 16, 32, 64, 128, 0, 0]);
+///
+/// let mut iter = sources::powers_of(10_u16, overflow::Saturating);
+/// assert_eq!(iter.next_array(), [1, 10, 100, 1000, 10000, 65535]);
+///
+/// let mut iter = sources::powers_of(-2_i8, overflow::Checked).skip(6);
+/// assert_eq!(iter.next_array(), [Some(64), Some(-128), None]);
+/// ```
+pub fn powers_of<T: Integer, P: Policy>(base: T, policy: P) -> Geometric<T, P> {
+    geometric(T::ONE, base, policy)
+}
+
+/// An infinite iterator over a geometric progression.
+///
+/// This `struct` is created by [`geometric`] and [`powers_of`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Geometric<T, P> {
+    /// The next item, or `None` if it has overflowed.
+    next: Option<T>,
+    ratio: T,
+    policy: P,
+}
+
+impl<T: Integer, P: Policy> Iterator for Geometric<T, P> {
+    type Item = P::Output<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<T: Integer, P: Policy> InfiniteIterator for Geometric<T, P> {
+    fn next_infinite(&mut self) -> Self::Item {
+        let (policy, ratio) = (self.policy, self.ratio);
+        let item = self.next;
+        self.next = item.and_then(|item| policy.mul(item, ratio));
+        policy.output(item)
+    }
+}
+
+/// Create an infinite iterator over the powers of `base`
+/// as arbitrary-precision [`Natural`]s, so the items never overflow.
+///
+/// This is the unbounded counterpart of [`powers_of`].
+/// The time and memory taken per item
+/// grow linearly with the number of items yielded so far.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut iter = sources::powers_of_big(10);
+/// assert_eq!(iter.next_array().map(|n| n.to_string()), ["1", "10", "100", "1000"]);
+///
+/// let three_50 = sources::powers_of_big(3).nth_infinite(50);
+/// assert_eq!(three_50.to_string(), "717897987691852588770249");
+///
+/// let two_64 = sources::powers_of_big(2).nth_infinite(64);
+/// assert_eq!(two_64.limbs(), [0, 1]);
+/// ```
+#[cfg(feature = "bignum")]
+pub fn powers_of_big(base: u64) -> PowersOfBig {
+    PowersOfBig {
+        next: Natural::from(1),
+        base,
+    }
+}
+
+/// An infinite iterator over the powers of a number as [`Natural`]s.
+///
+/// This `struct` is created by [`powers_of_big`].
+#[cfg(feature = "bignum")]
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PowersOfBig {
+    next: Natural,
+    base: u64,
+}
+
+#[cfg(feature = "bignum")]
+impl Iterator for PowersOfBig {
+    type Item = Natural;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(feature = "bignum")]
+impl InfiniteIterator for PowersOfBig {
+    fn next_infinite(&mut self) -> Self::Item {
+        let after = self.next.mul_small(self.base);
+        core::mem::replace(&mut self.next, after)
+    }
+}
@@ -0,0 +1,234 @@
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator over the output of the PCG32 pseudorandom number generator,
+/// seeded with `seed`.
+///
+/// This is [`pcg32_with_stream`] using the same stream
+/// as the reference implementation's default generator.
+/// PCG32 is small, fast and statistically strong,
+/// but it is not cryptographically secure.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut a = sources::pcg32(1234);
+/// let mut b = sources::pcg32(1234);
+/// assert_eq!(a.next_array::<8>(), b.next_array::<8>());
+///
+/// // Rolling a die.
+/// let roll = sources::pcg32(1234).map_infinite(|x| x % 6 + 1).nth_infinite(0);
+/// assert!((1..=6).contains(&roll));
+/// ```
+pub fn pcg32(seed: u64) -> Pcg32 {
+    pcg32_with_stream(seed, 0x6d1f_1ce5_ca5c_aded)
+}
+
+/// Create an infinite iterator over the output of the PCG32 pseudorandom number generator,
+/// seeded with `seed` and using the given stream.
+///
+/// Generators with different streams produce independent sequences
+/// even when given the same seed.
+/// Only the low 63 bits of `stream` are used.
+/// Seeding matches the reference implementation's `pcg32_srandom`.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut iter = sources::pcg32_with_stream(42, 54);
+/// assert_eq!(
+///     iter.next_array(),
+///     [0xa15c02b7, 0x7b47f409, 0xba1d3330, 0x83d2f293, 0xbfa4784b, 0xcbed606e],
+/// );
+/// ```
+pub fn pcg32_with_stream(seed: u64, stream: u64) -> Pcg32 {
+    let mut pcg = Pcg32 {
+        state: 0,
+        increment: (stream << 1) | 1,
+    };
+    pcg.step();
+    pcg.state = pcg.state.wrapping_add(seed);
+    pcg.step();
+    pcg
+}
+
+/// An infinite iterator over the output of the PCG32 pseudorandom number generator.
+///
+/// This `struct` is created by [`pcg32`] and [`pcg32_with_stream`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Pcg32 {
+    state: u64,
+    /// Always odd.
+    increment: u64,
+}
+
+impl Pcg32 {
+    const MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(Self::MULTIPLIER)
+            .wrapping_add(self.increment);
+    }
+}
+
+impl Iterator for Pcg32 {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl InfiniteIterator for Pcg32 {
+    fn next_infinite(&mut self) -> Self::Item {
+        let state = self.state;
+        self.step();
+        // XSH RR: xorshift the high bits down, then apply a random rotation.
+        let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+        let rotation = (state >> 59) as u32;
+        xorshifted.rotate_right(rotation)
+    }
+}
+
+/// Create an infinite iterator over the output of the SplitMix64 pseudorandom number generator,
+/// seeded with `seed`.
+///
+/// SplitMix64 accepts any seed, including zero,
+/// and spreads even very similar seeds far apart,
+/// so it is commonly used to derive seeds for other generators.
+/// It is not cryptographically secure.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIterator, InfiniteIteratorExt};
+///
+/// let mut iter = sources::splitmix64(1234567);
+/// assert_eq!(
+///     iter.next_array(),
+///     [6457827717110365317, 3203168211198807973, 9817491932198370423],
+/// );
+///
+/// // Seeding one generator from another.
+/// let mut seeds = sources::splitmix64(0);
+/// let pcg = sources::pcg32(seeds.next_infinite());
+/// let xorshift = sources::xorshift64star(seeds.next_infinite());
+/// ```
+pub fn splitmix64(seed: u64) -> SplitMix64 {
+    SplitMix64 { state: seed }
+}
+
+/// An infinite iterator over the output of the SplitMix64 pseudorandom number generator.
+///
+/// This `struct` is created by [`splitmix64`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    const GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+}
+
+impl Iterator for SplitMix64 {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        // The state is a Weyl sequence, so skipping ahead is a single multiplication.
+        self.state = self
+            .state
+            .wrapping_add((n as u64).wrapping_mul(Self::GAMMA));
+        self.next()
+    }
+}
+
+impl InfiniteIterator for SplitMix64 {
+    fn next_infinite(&mut self) -> Self::Item {
+        self.state = self.state.wrapping_add(Self::GAMMA);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// Create an infinite iterator over the output of the xorshift64* pseudorandom number generator,
+/// seeded with `seed`.
+///
+/// xorshift64* has a tiny state and is very fast,
+/// though its lowest bits are weaker than its highest.
+/// It is not cryptographically secure.
+///
+/// # Panics
+///
+/// Panics if `seed` is zero,
+/// since the generator would then only ever yield zero.
+/// Passing the seed through [`splitmix64`] first avoids this in practice.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut iter = sources::xorshift64star(1);
+/// assert_eq!(
+///     iter.next_array(),
+///     [5180492295206395165, 12380297144915551517, 13389498078930870103],
+/// );
+/// ```
+pub fn xorshift64star(seed: u64) -> Xorshift64Star {
+    assert!(seed != 0, "xorshift64* cannot be seeded with zero");
+    Xorshift64Star { state: seed }
+}
+
+/// An infinite iterator over the output of the xorshift64* pseudorandom number generator.
+///
+/// This `struct` is created by [`xorshift64star`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Xorshift64Star {
+    /// Never zero.
+    state: u64,
+}
+
+impl Iterator for Xorshift64Star {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl InfiniteIterator for Xorshift64Star {
+    fn next_infinite(&mut self) -> Self::Item {
+        let mut x = self.state;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x;
+        x.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+}
@@ -0,0 +1,236 @@
+use super::natural::Natural;
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator over the digits of π in the given radix.
+///
+/// The first item is the integer part, 3,
+/// and every item after it is a digit of the fractional part.
+///
+/// This uses Gibbons' unbounded spigot algorithm,
+/// so memory use and the time taken per digit
+/// both grow linearly with the number of digits yielded so far.
+///
+/// # Panics
+///
+/// Panics if `radix` is less than two.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut digits = sources::pi_digits(10);
+/// assert_eq!(digits.next_array(), [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5, 8, 9, 7, 9]);
+///
+/// let mut hex = sources::pi_digits(16);
+/// assert_eq!(hex.next_array(), [3, 2, 4, 3, 15, 6, 10, 8, 8, 8]);
+/// ```
+pub fn pi_digits(radix: u32) -> PiDigits {
+    PiDigits {
+        spigot: Spigot::new(radix),
+    }
+}
+
+/// An infinite iterator over the digits of π.
+///
+/// This `struct` is created by [`pi_digits`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PiDigits {
+    spigot: Spigot,
+}
+
+impl Iterator for PiDigits {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl InfiniteIterator for PiDigits {
+    fn next_infinite(&mut self) -> Self::Item {
+        // π = 2 + 1/3 (2 + 2/5 (2 + 3/7 (2 + …))),
+        // where every tail lies between 3 and 4.
+        self.spigot
+            .next_digit((3, 4), |k| (k, 4 * k + 2, 2 * k + 1))
+    }
+}
+
+/// Create an infinite iterator over the digits of e in the given radix.
+///
+/// The first item is the integer part, 2,
+/// and every item after it is a digit of the fractional part.
+///
+/// This uses an unbounded spigot algorithm,
+/// so memory use and the time taken per digit
+/// both grow linearly with the number of digits yielded so far.
+///
+/// # Panics
+///
+/// Panics if `radix` is less than two.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIteratorExt};
+///
+/// let mut digits = sources::e_digits(10);
+/// assert_eq!(digits.next_array(), [2, 7, 1, 8, 2, 8, 1, 8, 2, 8, 4, 5, 9, 0, 4]);
+///
+/// let mut binary = sources::e_digits(2);
+/// assert_eq!(binary.next_array(), [2, 1, 0, 1, 1, 0, 1, 1, 1, 1]);
+/// ```
+pub fn e_digits(radix: u32) -> EDigits {
+    EDigits {
+        spigot: Spigot::new(radix),
+    }
+}
+
+/// An infinite iterator over the digits of e.
+///
+/// This `struct` is created by [`e_digits`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct EDigits {
+    spigot: Spigot,
+}
+
+impl Iterator for EDigits {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl InfiniteIterator for EDigits {
+    fn next_infinite(&mut self) -> Self::Item {
+        // e = 1 + 1/1 (1 + 1/2 (1 + 1/3 (1 + …))),
+        // where every tail lies between 1 and 2.
+        self.spigot.next_digit((1, 2), |k| (1, k, k))
+    }
+}
+
+/// The state of a spigot over a series of linear fractional transformations
+/// `x ↦ (a x + b) / d`.
+///
+/// The transformations consumed so far are composed into `x ↦ (q x + r) / t`.
+#[derive(Debug, Clone)]
+struct Spigot {
+    q: Natural,
+    r: Int,
+    t: Natural,
+    /// The index of the last term consumed.
+    k: u64,
+    radix: u64,
+}
+
+impl Spigot {
+    fn new(radix: u32) -> Self {
+        assert!(radix >= 2, "radix must be at least two");
+        Self {
+            q: Natural::from(1),
+            r: Int::from(Natural::from(0)),
+            t: Natural::from(1),
+            k: 0,
+            radix: u64::from(radix),
+        }
+    }
+
+    /// Yield the next digit,
+    /// given the bounds every tail of the series lies within
+    /// and a function producing the `k`th term `(a, b, d)`.
+    fn next_digit(
+        &mut self,
+        (low, high): (u64, u64),
+        term: impl Fn(u64) -> (u64, u64, u64),
+    ) -> u32 {
+        loop {
+            let digit = self.floor_at(low);
+            if digit == self.floor_at(high) {
+                // Compose with `x ↦ radix (x - digit)`.
+                let shift = Int::from(self.t.mul_small(digit)).negate();
+                self.q = self.q.mul_small(self.radix);
+                self.r = self.r.add(&shift).mul_small(self.radix);
+                // The digit is less than the radix, apart from the integer part
+                // which is small.
+                return u32::try_from(digit).unwrap();
+            }
+
+            self.k += 1;
+            let (a, b, d) = term(self.k);
+            self.r = Int::from(self.q.mul_small(b)).add(&self.r.mul_small(d));
+            self.q = self.q.mul_small(a);
+            self.t = self.t.mul_small(d);
+        }
+    }
+
+    /// Compute `⌊(q x + r) / t⌋`.
+    fn floor_at(&self, x: u64) -> u64 {
+        let numerator = Int::from(self.q.mul_small(x)).add(&self.r);
+        debug_assert!(!numerator.negative);
+        numerator.magnitude.div_floor(&self.t)
+    }
+}
+
+/// An arbitrary-precision integer.
+#[derive(Debug, Clone)]
+struct Int {
+    /// Always `false` when the magnitude is zero.
+    negative: bool,
+    magnitude: Natural,
+}
+
+impl From<Natural> for Int {
+    fn from(magnitude: Natural) -> Self {
+        Self {
+            negative: false,
+            magnitude,
+        }
+    }
+}
+
+impl Int {
+    fn negate(self) -> Self {
+        Self {
+            negative: !self.negative && !self.magnitude.is_zero(),
+            magnitude: self.magnitude,
+        }
+    }
+
+    fn mul_small(&self, rhs: u64) -> Self {
+        let magnitude = self.magnitude.mul_small(rhs);
+        Self {
+            negative: self.negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        if self.negative == rhs.negative {
+            return Self {
+                negative: self.negative,
+                magnitude: self.magnitude.add(&rhs.magnitude),
+            };
+        }
+        let (larger, smaller) = if self.magnitude >= rhs.magnitude {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let magnitude = larger.magnitude.sub(&smaller.magnitude);
+        Self {
+            negative: larger.negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator over the Thue–Morse sequence
+/// `0, 1, 1, 0, 1, 0, 0, 1, …`, with each bit as a `bool`.
+///
+/// The `n`th item is whether `n` has an odd number of ones in binary.
+/// Each item is computed directly from its index,
+/// so this needs no allocation and skipping ahead is constant time.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIterator, InfiniteIteratorExt};
+///
+/// let mut iter = sources::thue_morse().map(u8::from);
+/// assert_eq!(iter.next_array(), [0, 1, 1, 0, 1, 0, 0, 1, 1, 0, 0, 1, 0, 1, 1, 0]);
+///
+/// assert!(sources::thue_morse().nth_infinite(1 << 30));
+/// ```
+pub fn thue_morse() -> ThueMorse {
+    ThueMorse { index: 0 }
+}
+
+/// An infinite iterator over the Thue–Morse sequence.
+///
+/// This `struct` is created by [`thue_morse`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ThueMorse {
+    index: u64,
+}
+
+impl Iterator for ThueMorse {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.index = self.index.wrapping_add(n as u64);
+        self.next()
+    }
+}
+
+impl InfiniteIterator for ThueMorse {
+    fn next_infinite(&mut self) -> Self::Item {
+        let item = self.index.count_ones() % 2 == 1;
+        self.index = self.index.wrapping_add(1);
+        item
+    }
+}
@@ -0,0 +1,74 @@
+use crate::InfiniteIterator;
+
+/// Create an infinite iterator counting up from `start`,
+/// wrapping back around to zero after the maximum value of the type.
+///
+/// Unlike [`RangeFrom`], which panics in debug builds
+/// once it goes past the maximum value,
+/// this iterator really does never end.
+///
+/// [`RangeFrom`]: core::ops::RangeFrom
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::{sources, InfiniteIterator, InfiniteIteratorExt};
+///
+/// let mut iter = sources::wrapping_range_from(254_u8);
+/// assert_eq!(iter.next_array(), [254, 255, 0, 1]);
+///
+/// // Skipping ahead is constant time.
+/// assert_eq!(iter.nth_infinite(253), 255);
+/// ```
+pub fn wrapping_range_from<T>(start: T) -> WrappingRangeFrom<T> {
+    WrappingRangeFrom { next: start }
+}
+
+/// An infinite iterator counting up and wrapping around on overflow.
+///
+/// This `struct` is created by [`wrapping_range_from`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WrappingRangeFrom<T> {
+    next: T,
+}
+
+impl<T> WrappingRangeFrom<T> {
+    /// Get a reference to the item that will be yielded next.
+    pub fn peek(&self) -> &T {
+        &self.next
+    }
+}
+
+macro_rules! impl_wrapping_range_from {
+    ($($t:ty)*) => {$(
+        impl Iterator for WrappingRangeFrom<$t> {
+            type Item = $t;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                Some(self.next_infinite())
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (usize::MAX, None)
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                // Truncation is intended: the counter wraps modulo the type's range anyway.
+                let n = n as $t;
+                self.next = self.next.wrapping_add(n);
+                self.next()
+            }
+        }
+
+        impl InfiniteIterator for WrappingRangeFrom<$t> {
+            fn next_infinite(&mut self) -> Self::Item {
+                let item = self.next;
+                self.next = self.next.wrapping_add(1);
+                item
+            }
+        }
+    )*};
+}
+
+impl_wrapping_range_from!(u8 u16 u32 u64 usize);
@@ -0,0 +1,183 @@
+use crate::InfiniteIterator;
+
+/// An [`InfiniteIterator`] whose items are [`Result`]s.
+///
+/// This trait is automatically implemented for every [`InfiniteIterator`]
+/// yielding `Result<T, E>`,
+/// such as [`TcpListener::incoming`].
+/// It is sealed; it cannot be implemented manually.
+///
+/// # Examples
+///
+/// ```
+/// use infinite_iterator::InfiniteTryIterator;
+///
+/// let mut iter = (0..)
+///     .map(|num| if num % 2 == 0 { Ok(num) } else { Err(num) })
+///     .map_ok(|num| num * 10);
+///
+/// assert_eq!(iter.try_next_infinite(), Ok(0));
+/// assert_eq!(iter.try_next_infinite(), Err(1));
+/// assert_eq!(iter.try_next_infinite(), Ok(20));
+/// ```
+///
+/// [`TcpListener::incoming`]: https://doc.rust-lang.org/stable/std/net/struct.TcpListener.html#method.incoming
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub trait InfiniteTryIterator:
+    InfiniteIterator<Item = Result<Self::Ok, Self::Error>> + try_iterator::Sealed
+{
+    /// The type of successful items yielded by the iterator.
+    type Ok;
+
+    /// The type of errors yielded by the iterator.
+    type Error;
+
+    /// Like [`InfiniteIterator::next_infinite`],
+    /// but with the item's type expressed as
+    /// a result of [`Self::Ok`] and [`Self::Error`].
+    fn try_next_infinite(&mut self) -> Result<Self::Ok, Self::Error>;
+
+    /// Apply a function to every successful item,
+    /// passing errors through unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteTryIterator;
+    ///
+    /// let mut iter = core::iter::repeat(Ok::<_, ()>(1)).map_ok(|num| num + 1);
+    /// assert_eq!(iter.try_next_infinite(), Ok(2));
+    /// ```
+    fn map_ok<U, F>(self, f: F) -> MapOk<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Ok) -> U,
+    {
+        MapOk { iter: self, f }
+    }
+
+    /// Skip all successful items that do not satisfy the predicate,
+    /// passing errors through unchanged.
+    ///
+    /// There must be infinitely many errors or items satisfying the predicate,
+    /// otherwise the resulting iterator will loop forever
+    /// looking for the next item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use infinite_iterator::InfiniteTryIterator;
+    ///
+    /// let mut iter = (0..)
+    ///     .map(|num| if num == 3 { Err(num) } else { Ok(num) })
+    ///     .filter_ok(|&num| num > 1);
+    ///
+    /// assert_eq!(iter.try_next_infinite(), Ok(2));
+    /// assert_eq!(iter.try_next_infinite(), Err(3));
+    /// assert_eq!(iter.try_next_infinite(), Ok(4));
+    /// ```
+    fn filter_ok<P>(self, predicate: P) -> FilterOk<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Ok) -> bool,
+    {
+        FilterOk {
+            iter: self,
+            predicate,
+        }
+    }
+}
+
+mod try_iterator {
+    pub trait Sealed {}
+}
+
+impl<I, T, E> try_iterator::Sealed for I where I: ?Sized + InfiniteIterator<Item = Result<T, E>> {}
+impl<I, T, E> InfiniteTryIterator for I
+where
+    I: ?Sized + InfiniteIterator<Item = Result<T, E>>,
+{
+    type Ok = T;
+    type Error = E;
+
+    fn try_next_infinite(&mut self) -> Result<Self::Ok, Self::Error> {
+        self.next_infinite()
+    }
+}
+
+/// An iterator that maps the successful items of an [`InfiniteTryIterator`].
+///
+/// This `struct` is created by [`InfiniteTryIterator::map_ok`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F, U> Iterator for MapOk<I, F>
+where
+    I: InfiniteTryIterator,
+    F: FnMut(I::Ok) -> U,
+{
+    type Item = Result<U, I::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, U> InfiniteIterator for MapOk<I, F>
+where
+    I: InfiniteTryIterator,
+    F: FnMut(I::Ok) -> U,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        self.iter.try_next_infinite().map(&mut self.f)
+    }
+}
+
+/// An iterator that filters the successful items of an [`InfiniteTryIterator`].
+///
+/// This `struct` is created by [`InfiniteTryIterator::filter_ok`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FilterOk<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> Iterator for FilterOk<I, P>
+where
+    I: InfiniteTryIterator,
+    P: FnMut(&I::Ok) -> bool,
+{
+    type Item = Result<I::Ok, I::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.next_infinite())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<I, P> InfiniteIterator for FilterOk<I, P>
+where
+    I: InfiniteTryIterator,
+    P: FnMut(&I::Ok) -> bool,
+{
+    fn next_infinite(&mut self) -> Self::Item {
+        loop {
+            match self.iter.try_next_infinite() {
+                Ok(item) if !(self.predicate)(&item) => {}
+                result => break result,
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::InfiniteIterator;
+
+/// A tuple whose elements all have the same type.
+///
+/// This trait is implemented for tuples of two, three and four elements,
+/// and is used by
+/// [`InfiniteIteratorExt::tuple_windows`](crate::InfiniteIteratorExt::tuple_windows)
+/// and [`InfiniteIteratorExt::tuples`](crate::InfiniteIteratorExt::tuples)
+/// to choose the size of the tuples they yield.
+/// It is sealed; it cannot be implemented manually.
+pub trait HomogeneousTuple: homogeneous_tuple::Sealed {}
+
+impl<T: homogeneous_tuple::Sealed> HomogeneousTuple for T {}
+
+pub(crate) mod homogeneous_tuple {
+    use crate::InfiniteIterator;
+
+    pub trait Sealed: Sized {
+        /// The type of every element of the tuple.
+        type Item;
+
+        /// The number of elements in the tuple.
+        const ARITY: usize;
+
+        /// Build a tuple from the next `ARITY` items of an iterator.
+        fn from_infinite_iter<I>(iter: &mut I) -> Self
+        where
+            I: ?Sized + InfiniteIterator<Item = Self::Item>;
+
+        /// Drop the first element,
+        /// shift every other element one place to the left,
+        /// and put `item` in the last place.
+        fn shift(self, item: Self::Item) -> Self;
+    }
+}
+
+macro_rules! homogeneous_tuples {
+    ($($arity:literal => ($first:ident $(, $rest:ident)*),)*) => {$(
+        impl<T> homogeneous_tuple::Sealed for (T, $(element_type!($rest),)*) {
+            type Item = T;
+
+            const ARITY: usize = $arity;
+
+            fn from_infinite_iter<I>(iter: &mut I) -> Self
+            where
+                I: ?Sized + InfiniteIterator<Item = Self::Item>,
+            {
+                (iter.next_infinite(), $(element_type!($rest => iter.next_infinite()),)*)
+            }
+
+            fn shift(self, item: Self::Item) -> Self {
+                let (_, $($rest,)*) = self;
+                ($($rest,)* item,)
+            }
+        }
+    )*};
+}
+
+macro_rules! element_type {
+    ($ignored:ident) => {
+        T
+    };
+    ($ignored:ident => $expr:expr) => {
+        $expr
+    };
+}
+
+homogeneous_tuples! {
+    2 => (a, b),
+    3 => (a, b, c),
+    4 => (a, b, c, d),
+}
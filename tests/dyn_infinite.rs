@@ -0,0 +1,112 @@
+#![cfg(feature = "alloc")]
+
+use infinite_iterator::{BoxedInfinite, InfiniteIterator, InfiniteIteratorExt};
+
+#[test]
+fn boxed() {
+    let mut iter: BoxedInfinite<'_, u32> = (0..).boxed();
+    assert_eq!(iter.next_infinite(), 0);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.size_hint().1, None);
+    assert_eq!(iter.find_infinite(|&num| num > 5), 6);
+}
+
+#[test]
+fn boxed_borrowing() {
+    let items = [1, 2, 3];
+    let mut iter = items.iter().cycle().chain(core::iter::repeat(&0));
+    let mut iter = (&mut iter).copied().map(|num| num * 2).boxed();
+    assert_eq!(iter.next_infinite(), 2);
+    assert_eq!(iter.next_infinite(), 4);
+}
+
+#[test]
+fn by_mut_ref() {
+    let mut inner = (0_u32..).step_by(10);
+    let iter: &mut dyn InfiniteIterator<Item = u32> = &mut inner;
+    assert_eq!(iter.next_infinite(), 0);
+    let mut by_ref = iter.map_infinite(|num| num + 1);
+    assert_eq!(by_ref.next_infinite(), 11);
+    assert_eq!(inner.next_infinite(), 20);
+}
+
+#[test]
+fn boxed_adapters() {
+    let iter: BoxedInfinite<'_, u32> = Box::new(0..);
+    let mut iter = iter.zip_infinite(core::iter::repeat('a')).enumerate();
+    assert_eq!(iter.next_infinite(), (0, (0, 'a')));
+    assert_eq!(iter.position_infinite(|(_, (num, _))| num == 3), 2);
+}
+
+#[test]
+fn next_array() {
+    let mut iter: BoxedInfinite<'_, u32> = Box::new((0..).step_by(2));
+    assert_eq!(iter.next_array(), [0, 2, 4]);
+    let by_ref: &mut dyn InfiniteIterator<Item = u32> = &mut iter;
+    assert_eq!(by_ref.next_array(), [6, 8]);
+}
+
+#[test]
+fn next_chunk_single_dynamic_call() {
+    struct Counted {
+        next: u32,
+        chunk_calls: u32,
+    }
+
+    impl Iterator for Counted {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            Some(self.next_infinite())
+        }
+    }
+
+    impl InfiniteIterator for Counted {
+        fn next_infinite(&mut self) -> Self::Item {
+            self.next += 1;
+            self.next - 1
+        }
+
+        fn next_chunk_into(&mut self, chunk: &mut infinite_iterator::ChunkWriter<'_, u32>) {
+            self.chunk_calls += 1;
+            while !chunk.is_full() {
+                chunk.push(self.next_infinite());
+            }
+        }
+    }
+
+    let mut inner = Counted {
+        next: 0,
+        chunk_calls: 0,
+    };
+    let mut boxed: BoxedInfinite<'_, u32> = Box::new(&mut inner);
+    assert_eq!(boxed.next_array::<4>(), [0, 1, 2, 3]);
+    assert_eq!(boxed.next_array(), [4, 5]);
+    drop(boxed);
+    assert_eq!(inner.chunk_calls, 2);
+}
+
+#[test]
+#[should_panic = "`next_chunk_into` did not fill the whole chunk"]
+fn next_chunk_unfilled() {
+    struct Lazy;
+
+    impl Iterator for Lazy {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            Some(0)
+        }
+    }
+
+    impl InfiniteIterator for Lazy {
+        fn next_infinite(&mut self) -> Self::Item {
+            0
+        }
+
+        fn next_chunk_into(&mut self, _: &mut infinite_iterator::ChunkWriter<'_, u32>) {}
+    }
+
+    let iter: &mut dyn InfiniteIterator<Item = u32> = &mut Lazy;
+    let _: [u32; 2] = iter.next_array();
+}
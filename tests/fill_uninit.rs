@@ -0,0 +1,57 @@
+//! These tests exercise the `unsafe` code in `fill_uninit`.
+//! Besides running normally, CI runs them under Miri
+//! (see `.github/workflows/miri.yml`):
+//!
+//! ```sh
+//! cargo +nightly miri test --all-features --test fill_uninit
+//! ```
+
+use core::cell::Cell;
+use core::mem::MaybeUninit;
+use infinite_iterator::InfiniteIteratorExt;
+
+#[test]
+fn initializes_every_element() {
+    let mut buffer = [MaybeUninit::<u8>::uninit(); 16];
+    let filled = (0..).fill_uninit(&mut buffer);
+    assert_eq!(filled.len(), 16);
+    assert!(filled.iter().copied().eq(0..16));
+}
+
+#[test]
+fn empty_slice() {
+    let mut iter = 0..;
+    let filled = iter.fill_uninit(&mut []);
+    assert!(filled.is_empty());
+    assert_eq!(iter.next(), Some(0));
+}
+
+#[test]
+fn returned_slice_is_mutable() {
+    let mut buffer = [MaybeUninit::<u8>::uninit(); 4];
+    let filled = (0..).fill_uninit(&mut buffer);
+    filled[2] = 100;
+    assert_eq!(filled, [0, 1, 100, 3]);
+}
+
+#[test]
+fn items_are_not_dropped() {
+    struct Counted<'a>(&'a Cell<usize>);
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let drops = Cell::new(0);
+    let mut buffer: [MaybeUninit<Counted<'_>>; 3] = [(); 3].map(|()| MaybeUninit::uninit());
+    let filled = core::iter::repeat_with(|| Counted(&drops)).fill_uninit(&mut buffer);
+    assert_eq!(filled.len(), 3);
+    assert_eq!(drops.get(), 0);
+
+    for item in filled {
+        // SAFETY: Each element is initialized and is not used again afterward.
+        unsafe { core::ptr::drop_in_place(item) };
+    }
+    assert_eq!(drops.get(), 3);
+}
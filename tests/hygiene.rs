@@ -7,3 +7,10 @@ fn hygiene() {
         ::std::println!("{x}");
     });
 }
+
+#[test]
+fn izip_hygiene() {
+    let mut iter = ::infinite_iterator::izip!(0_u32.., 1_u32.., 2_u32.., 3_u32..);
+    let item = ::infinite_iterator::InfiniteIterator::next_infinite(&mut iter);
+    ::std::assert_eq!(item, (0, 1, 2, 3));
+}
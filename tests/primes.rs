@@ -0,0 +1,24 @@
+#![cfg(feature = "alloc")]
+//! These tests check the incremental sieve against a plain sieve of Eratosthenes,
+//! across the points where new primes start being sieved by.
+
+use infinite_iterator::sources;
+use infinite_iterator::InfiniteIteratorExt;
+
+#[test]
+fn matches_plain_sieve() {
+    const LIMIT: usize = 200_000;
+    let mut is_composite = vec![false; LIMIT];
+    let mut expected = Vec::new();
+    for n in 2..LIMIT {
+        if !is_composite[n] {
+            expected.push(n as u64);
+            for multiple in (n * n..LIMIT).step_by(n) {
+                is_composite[multiple] = true;
+            }
+        }
+    }
+
+    let primes: Vec<u64> = sources::primes().collect_n(expected.len());
+    assert_eq!(primes, expected);
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "alloc")]
+//! These tests check the spigot sources against many digits,
+//! well past where any fixed-precision arithmetic would break down.
+
+use infinite_iterator::sources;
+use infinite_iterator::InfiniteIteratorExt;
+
+const PI: &[&str] = &[
+    "3141592653589793238462643383279502884197169399375105820974944592",
+    "3078164062862089986280348253421170679821480865132823066470938446",
+    "0955058223172535940812848111745028410270193852110555964462294895",
+    "4930381964428810975665933446128475648233786783165271201909145648",
+    "5669234603486104543266482133936072602491412737245870066063155881",
+    "7488152092096282925409171536436789259036001133053054882046652138",
+    "4146951941511609433057270365759591953092186117381932611793105118",
+    "54807446237996274956735188575272489122793818301194912",
+];
+
+const E: &[&str] = &[
+    "2718281828459045235360287471352662497757247093699959574966967627",
+    "7240766303535475945713821785251664274274663919320030599218174135",
+    "9662904357290033429526059563073813232862794349076323382988075319",
+    "5251019011573834187930702154089149934884167509244761460668082264",
+    "8001684774118537423454424371075390777449920695517027618386062613",
+    "3138458300075204493382656029760673711320070932870912744374704723",
+    "0696977209310141692836819025515108657463772111252389784425056953",
+    "69677078544996996794686445490598793163688923009879312",
+];
+
+fn expected(chunks: &[&str]) -> Vec<u32> {
+    chunks
+        .iter()
+        .flat_map(|chunk| chunk.chars())
+        .map(|c| c.to_digit(10).unwrap())
+        .collect()
+}
+
+#[test]
+fn pi() {
+    let expected = expected(PI);
+    let digits: Vec<u32> = sources::pi_digits(10).collect_n(expected.len());
+    assert_eq!(digits, expected);
+}
+
+#[test]
+fn e() {
+    let expected = expected(E);
+    let digits: Vec<u32> = sources::e_digits(10).collect_n(expected.len());
+    assert_eq!(digits, expected);
+}
@@ -0,0 +1,41 @@
+//! Adapters skip the panicking branch of `next_infinite`
+//! only when their underlying iterator is `TrustedInfinite`,
+//! so adapters over an untrusted iterator must still panic when it ends.
+
+use core::iter;
+use infinite_iterator::{
+    assert_infinite, assume_infinite, InfiniteDoubleEndedIterator, InfiniteIterator,
+};
+
+#[test]
+#[should_panic = "iterator passed to `assert_infinite` ended"]
+fn untrusted_adapter_still_panics() {
+    let mut iter = assert_infinite([1]).map(|x| x + 1);
+    assert_eq!(iter.next_infinite(), 2);
+    iter.next_infinite();
+}
+
+#[test]
+#[should_panic = "iterator passed to `assert_infinite` ended"]
+fn untrusted_half_of_zip_still_panics() {
+    let mut iter = (0_u32..).zip(assert_infinite([1]));
+    assert_eq!(iter.next_infinite(), (0, 1));
+    iter.next_infinite();
+}
+
+#[test]
+fn trusted_adapters_yield_the_same_items() {
+    // SAFETY: `Cycle` over a non-empty slice never ends.
+    let cycle = unsafe { assume_infinite([1, 2].iter().cycle()) };
+    let mut iter = cycle.copied().zip(iter::repeat(10)).map(|(a, b)| a + b);
+    assert_eq!(iter.next_infinite(), 11);
+    assert_eq!(iter.next_infinite(), 12);
+    assert_eq!(iter.next_infinite(), 11);
+}
+
+#[test]
+fn trusted_adapters_from_the_back() {
+    let mut iter = iter::repeat(&3).copied().map(|x| x * 2);
+    assert_eq!(iter.next_back_infinite(), 6);
+    assert_eq!(iter.rev().next_infinite(), 6);
+}